@@ -5,16 +5,29 @@ use crate::GameState::{EndGame, GameOverScreen, InGame, Menu, Paused};
 use crate::fireworks::{Firework, FireworkPlugin};
 use crate::menu::MenuPlugin;
 use ColorPiece::White;
-use TurnState::{AiThinking, AiWaiting, HumanTurn};
+use TurnState::{AiThinking, HumanTurn};
 use bevy::ecs::relationship::RelatedSpawnerCommands;
 use bevy::input::common_conditions::input_just_pressed;
 use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task, block_on, poll_once};
 use bevy::window::{PresentMode, WindowResolution};
+use reversi_core::application::game_record::GameRecord;
+use reversi_core::application::game_repository::GameRepository;
+use reversi_core::application::seats::{Controller, Seats};
 use reversi_core::application::use_case::UseCase;
 use reversi_core::domain::board::ColorPiece::Black;
 use reversi_core::domain::board::{Board, BoardIter, Case, ColorPiece};
+use reversi_core::domain::search::SearchControl;
+use reversi_core::infrastructure::file_game_repository::FileGameRepository;
+use reversi_core::presentation::labels::{move_error_message, pass_message};
 
 const CELL_SIZE: f32 = 60f32;
+/// Répertoire des parties sauvegardées, dans le répertoire courant
+const SAVE_DIRECTORY: &str = "reversi-saves";
+/// La sauvegarde du menu pause remplace la précédente
+const SAVE_ID: &str = "pause";
+/// Temps minimal avant le coup de l'IA, en secondes
+const AI_MOVE_DELAY: f32 = 0.3;
 
 #[derive(Resource)]
 struct BoardResource(Board);
@@ -22,6 +35,17 @@ struct BoardResource(Board);
 #[derive(Resource)]
 struct UseCaseResource(UseCase);
 
+/// Partie en cours telle qu'elle sera sauvegardée
+#[derive(Resource)]
+struct RecordResource(GameRecord);
+
+#[derive(Resource)]
+struct RepositoryResource(Box<dyn GameRepository>);
+
+/// Position de départ optionnelle : "<64 cases> <couleur au trait>"
+#[derive(Resource)]
+struct StartPosition(Option<String>);
+
 #[derive(Resource)]
 struct GameAssets {
     pawn_atlas_layout: Handle<TextureAtlasLayout>,
@@ -55,6 +79,43 @@ struct MoveProcessed {
     position: (usize, usize),
     pieces_to_flip: Vec<(usize, usize)>,
     player: ColorPiece,
+    pass: Option<ColorPiece>,
+}
+
+#[derive(Component)]
+struct Notice(Timer);
+
+/// Recherche de l'IA lancée sur le pool de tâches, pour ne pas bloquer l'affichage
+#[derive(Component)]
+struct AiSearch {
+    task: Task<Option<(usize, usize)>>,
+    control: SearchControl,
+    reported_depth: u8,
+    /// Le coup trouvé n'est joué qu'après ce délai, pour laisser voir le coup précédent. L'IA
+    /// cherche déjà pendant ce temps : il ne retarde que les coups trouvés aussitôt, comme ceux
+    /// du livre d'ouvertures.
+    delay: Timer,
+}
+
+#[derive(Event)]
+struct AiProgress {
+    depth: u8,
+    nodes: u64,
+}
+
+#[derive(Event)]
+struct AiMoveFound {
+    position: (usize, usize),
+}
+
+#[derive(Component)]
+struct ThinkingText;
+
+/// Partie sauvegardée relue, prête à reprendre
+#[derive(Event)]
+struct GameLoaded {
+    record: GameRecord,
+    board: Board,
 }
 
 #[derive(SubStates, Debug, Clone, PartialEq, Eq, Hash, Default)]
@@ -62,13 +123,9 @@ struct MoveProcessed {
 enum TurnState {
     #[default]
     HumanTurn,
-    AiWaiting,
     AiThinking,
 }
 
-#[derive(Resource)]
-struct AiTimer(Timer);
-
 #[derive(Resource)]
 struct EndGameAnimation {
     black_to_spawn: usize,
@@ -81,6 +138,7 @@ struct EndGameAnimation {
 #[derive(Resource)]
 pub struct GameConfig {
     pub show_playable_indicators: bool,
+    pub seats: Seats,
 }
 
 #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
@@ -98,14 +156,23 @@ enum GameState {
 fn main() {
     let use_case = UseCase::default();
     let board = use_case.initialize_game_use_case.execute();
+    let record = GameRecord::new(&board, Seats::default());
 
     App::new()
         // .insert_resource(WinitSettings::desktop_app())
         .insert_resource(BoardResource(board))
+        .insert_resource(RecordResource(record))
+        .insert_resource(RepositoryResource(Box::new(FileGameRepository::new(
+            SAVE_DIRECTORY,
+        ))))
         .insert_resource(UseCaseResource(use_case))
-        .insert_resource(AiTimer(Timer::from_seconds(1.0, TimerMode::Once)))
+        .insert_resource(StartPosition(
+            Some(std::env::args().skip(1).collect::<Vec<_>>().join(" "))
+                .filter(|position| !position.is_empty()),
+        ))
         .insert_resource(GameConfig {
             show_playable_indicators: true,
+            seats: Seats::default(),
         })
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
@@ -124,10 +191,15 @@ fn main() {
         .add_plugins((MenuPlugin, FireworkPlugin))
         .add_systems(Startup, setup_game)
         .add_systems(Update, tick_despawn_timers)
+        // Reprendre après une pause ne doit pas recommencer la partie
         .add_systems(
-            OnEnter(InGame),
-            (create_board_instance, create_board_ui).chain(),
+            OnTransition {
+                exited: Menu,
+                entered: InGame,
+            },
+            (apply_seats, create_board_instance).chain(),
         )
+        .add_systems(OnEnter(InGame), (create_board_ui, start_turn).chain())
         .add_systems(OnExit(InGame), remove_board)
         .add_systems(
             Update,
@@ -137,7 +209,14 @@ fn main() {
         .add_systems(OnExit(Paused), cleanup_pause_menu)
         .add_systems(
             Update,
-            (handle_pause_resume, handle_pause_quit).run_if(in_state(Paused)),
+            (
+                handle_pause_resume,
+                handle_pause_restart,
+                handle_pause_save,
+                handle_pause_load,
+                handle_pause_quit,
+            )
+                .run_if(in_state(Paused)),
         )
         .add_systems(
             OnEnter(EndGame),
@@ -148,7 +227,19 @@ fn main() {
         .add_observer(check_end_game_observer)
         .add_observer(apply_move)
         .add_observer(execute_player_move)
+        .add_observer(show_pass_notice)
+        .add_observer(play_ai_move)
+        .add_observer(show_ai_progress)
+        .add_observer(restore_game)
+        .add_systems(OnEnter(AiThinking), start_ai_search)
+        .add_systems(OnExit(AiThinking), cancel_ai_search)
+        .add_systems(Update, tick_notices)
+        .add_systems(OnExit(InGame), remove_notices)
         .add_systems(Update, show_playable_moves.run_if(in_state(HumanTurn)))
+        .add_systems(
+            Update,
+            undo_last_move.run_if(in_state(HumanTurn).and(input_just_pressed(KeyCode::Backspace))),
+        )
         .add_systems(OnExit(HumanTurn), hide_playable_moves)
         .add_systems(OnEnter(GameOverScreen), setup_game_over_screen)
         .add_systems(OnExit(GameOverScreen), cleanup_game_over)
@@ -156,8 +247,7 @@ fn main() {
             Update,
             (
                 handle_click.run_if(in_state(HumanTurn).and(input_just_pressed(MouseButton::Left))),
-                ai_wait_system.run_if(in_state(AiWaiting)),
-                ai_play_system.run_if(in_state(AiThinking)),
+                poll_ai_search.run_if(in_state(AiThinking)),
             )
                 .chain(),
         )
@@ -212,26 +302,53 @@ fn execute_player_move(
     mut commands: Commands,
     mut game_res: ResMut<BoardResource>,
     use_case: ResMut<UseCaseResource>,
+    config: Res<GameConfig>,
     mut next_state: ResMut<NextState<TurnState>>,
 ) {
     let board = &mut game_res.0;
-    let option = use_case
+    let player = board.current_player().color();
+    let result = use_case
         .0
         .play_move_use_case
         .execute(board, move_accepted.x, move_accepted.y);
-    if let Some(flip_pieces) = option {
-        commands.trigger(MoveProcessed {
-            position: (move_accepted.x, move_accepted.y),
-            pieces_to_flip: flip_pieces,
-            player: Black,
-        });
-
-        if board.player2() {
-            next_state.set(AiWaiting);
+    match result {
+        Ok(outcome) => {
+            let pass = outcome.pass();
+            commands.trigger(MoveProcessed {
+                position: (move_accepted.x, move_accepted.y),
+                pieces_to_flip: outcome.into_flipped(),
+                player,
+                pass,
+            });
+            next_state.set(next_turn(&config.seats, board));
         }
+        Err(error) => spawn_notice(&mut commands, move_error_message(error)),
+    }
+}
+
+fn next_turn(seats: &Seats, board: &Board) -> TurnState {
+    match seats.to_move(board) {
+        Controller::Ai(_) => AiThinking,
+        // Rien ne joue pour un joueur externe : ces places ne sont jamais proposées ni chargées
+        Controller::Human | Controller::External => HumanTurn,
     }
 }
 
+/// Bevy ne sait pas recevoir les coups d'un autre programme
+fn has_external_seat(seats: &Seats) -> bool {
+    [Black, White]
+        .into_iter()
+        .any(|color| seats.controller(color) == Controller::External)
+}
+
+fn start_turn(
+    game_res: Res<BoardResource>,
+    config: Res<GameConfig>,
+    mut next_state: ResMut<NextState<TurnState>>,
+) {
+    next_state.set(next_turn(&config.seats, &game_res.0));
+}
+
 fn setup_game(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -253,11 +370,36 @@ fn setup_game(
     next_state.set(Menu);
 }
 
-fn create_board_instance(use_case: ResMut<UseCaseResource>, mut game_res: ResMut<BoardResource>) {
-    let board = use_case.0.initialize_game_use_case.execute();
+fn apply_seats(mut use_case: ResMut<UseCaseResource>, config: Res<GameConfig>) {
+    use_case.0.set_seats(&config.seats);
+}
+
+fn create_board_instance(
+    use_case: ResMut<UseCaseResource>,
+    start_position: Res<StartPosition>,
+    config: Res<GameConfig>,
+    mut game_res: ResMut<BoardResource>,
+    mut record: ResMut<RecordResource>,
+) {
+    let board = new_board(&use_case.0, &start_position);
+    record.0 = GameRecord::new(&board, config.seats);
     game_res.0 = board;
 }
 
+/// Plateau de départ d'une nouvelle partie, depuis la position donnée au lancement s'il y en a
+fn new_board(use_case: &UseCase, start_position: &StartPosition) -> Board {
+    let start_game_use_case = &use_case.initialize_game_use_case;
+    match &start_position.0 {
+        Some(position) => start_game_use_case
+            .execute_from_position(position)
+            .unwrap_or_else(|error| {
+                warn!("Position ignorée : {}", error);
+                start_game_use_case.execute()
+            }),
+        None => start_game_use_case.execute(),
+    }
+}
+
 fn create_board_ui(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -343,43 +485,225 @@ fn handle_click(
     }
 }
 
-fn ai_wait_system(
-    time: Res<Time>,
-    mut ai_timer: ResMut<AiTimer>,
+fn undo_last_move(
+    mut commands: Commands,
+    mut game_res: ResMut<BoardResource>,
+    pieces: Query<Entity, With<CaseUi>>,
+    board_root: Single<Entity, With<BoardRoot>>,
+    assets: Res<GameAssets>,
+    config: Res<GameConfig>,
     mut next_state: ResMut<NextState<TurnState>>,
 ) {
-    ai_timer.0.tick(time.delta());
-    if ai_timer.0.just_finished() {
-        next_state.set(AiThinking);
-        ai_timer.0.reset();
+    let board = &mut game_res.0;
+    // Annule aussi les réponses de l'IA pour revenir au dernier coup d'un joueur humain
+    while board.undo().is_some() && config.seats.to_move(board) != Controller::Human {}
+
+    for entity in &pieces {
+        commands.entity(entity).despawn();
     }
+    commands.entity(board_root.entity()).with_children(|parent| {
+        for (x, y) in BoardIter::default() {
+            if let Some(Case::Piece(color)) = board.cell(x, y) {
+                add_piece(parent, x, y, color, &assets);
+            }
+        }
+    });
+    // Au début de la partie, le premier joueur peut être l'IA
+    next_state.set(next_turn(&config.seats, board));
+}
+
+fn start_ai_search(
+    mut commands: Commands,
+    game: Res<BoardResource>,
+    use_case: Res<UseCaseResource>,
+) {
+    let control = SearchControl::default();
+    let search = use_case
+        .0
+        .play_ai_move_use_case
+        .search(&game.0, control.clone());
+    let task = AsyncComputeTaskPool::get().spawn(async move { search.run() });
+    commands.spawn(AiSearch {
+        task,
+        control,
+        reported_depth: 0,
+        delay: Timer::from_seconds(AI_MOVE_DELAY, TimerMode::Once),
+    });
+    commands.spawn((
+        ThinkingText,
+        Text::new("L'IA réfléchit..."),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+    ));
 }
 
-fn ai_play_system(
+fn poll_ai_search(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut searches: Query<(Entity, &mut AiSearch)>,
+    game: Res<BoardResource>,
+    config: Res<GameConfig>,
+    mut turn_state: ResMut<NextState<TurnState>>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    for (entity, mut search) in &mut searches {
+        if let Some(progress) = search.control.progress()
+            && progress.depth != search.reported_depth
+        {
+            search.reported_depth = progress.depth;
+            commands.trigger(AiProgress {
+                depth: progress.depth,
+                nodes: progress.nodes,
+            });
+        }
+        if !search.delay.tick(time.delta()).is_finished() {
+            continue;
+        }
+        let Some(result) = block_on(poll_once(&mut search.task)) else {
+            continue;
+        };
+        commands.entity(entity).despawn();
+        match result {
+            Some(position) => commands.trigger(AiMoveFound { position }),
+            None => end_ai_turn(&game.0, &config.seats, &mut turn_state, &mut game_state),
+        }
+    }
+}
+
+/// Passe au tour suivant quand l'IA n'a rien joué, ou à la fin de partie si plus personne ne
+/// peut jouer, pour ne pas rester bloqué dans `AiThinking`
+fn end_ai_turn(
+    board: &Board,
+    seats: &Seats,
+    turn_state: &mut NextState<TurnState>,
+    game_state: &mut NextState<GameState>,
+) {
+    if board.end_of_game().is_some() {
+        game_state.set(EndGame);
+    } else {
+        turn_state.set(next_turn(seats, board));
+    }
+}
+
+fn show_ai_progress(
+    ai_progress: On<AiProgress>,
+    mut texts: Query<&mut Text, With<ThinkingText>>,
+) {
+    for mut text in &mut texts {
+        text.0 = format!(
+            "L'IA réfléchit... profondeur {} ({} positions)",
+            ai_progress.depth, ai_progress.nodes
+        );
+    }
+}
+
+/// Arrête la recherche en cours quand la partie est mise en pause ou quittée
+fn cancel_ai_search(
+    mut commands: Commands,
+    searches: Query<(Entity, &AiSearch)>,
+    texts: Query<Entity, With<ThinkingText>>,
+) {
+    for (entity, search) in &searches {
+        search.control.cancel();
+        commands.entity(entity).despawn();
+    }
+    for entity in &texts {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn play_ai_move(
+    ai_move: On<AiMoveFound>,
     mut commands: Commands,
     mut game: ResMut<BoardResource>,
-    use_case: ResMut<UseCaseResource>,
+    use_case: Res<UseCaseResource>,
+    config: Res<GameConfig>,
     mut next_state: ResMut<NextState<TurnState>>,
+    mut game_state: ResMut<NextState<GameState>>,
 ) {
     let board = &mut game.0;
-    let use_case = &use_case.0;
-    let move_ia = use_case.play_ai_move_use_case.execute(board);
-
-    if let Some(selected_move) = move_ia {
-        commands.trigger(MoveProcessed {
-            position: selected_move.position(),
-            pieces_to_flip: selected_move.pieces_to_flip(),
-            player: White,
+    let player = board.current_player().color();
+    let move_ia = use_case
+        .0
+        .play_ai_move_use_case
+        .play(board, ai_move.position);
+
+    match move_ia {
+        Ok(selected_move) => {
+            commands.trigger(MoveProcessed {
+                position: selected_move.position(),
+                pass: selected_move.pass(),
+                pieces_to_flip: selected_move.pieces_to_flip(),
+                player,
+            });
+            next_state.set(next_turn(&config.seats, board));
+        }
+        Err(error) => {
+            warn!("Coup de l'IA refusé : {}", error);
+            spawn_notice(&mut commands, move_error_message(error));
+            end_ai_turn(board, &config.seats, &mut next_state, &mut game_state);
+        }
+    }
+}
+
+fn show_pass_notice(move_processed: On<MoveProcessed>, mut commands: Commands) {
+    if let Some(color) = move_processed.pass {
+        spawn_notice(&mut commands, pass_message(color));
+    }
+}
+
+fn spawn_notice(commands: &mut Commands, text: &str) {
+    commands
+        .spawn((
+            Notice(Timer::from_seconds(1.5, TimerMode::Once)),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
         });
+}
 
-        if board.player1() {
-            next_state.set(HumanTurn);
-        } else if board.player2() {
-            next_state.set(AiWaiting);
+fn tick_notices(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Notice)>,
+) {
+    for (entity, mut notice) in &mut query {
+        notice.0.tick(time.delta());
+        if notice.0.just_finished() {
+            commands.entity(entity).despawn();
         }
     }
 }
 
+fn remove_notices(mut commands: Commands, query: Query<Entity, With<Notice>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
+
 fn add_piece(
     commands: &mut RelatedSpawnerCommands<ChildOf>,
     x: usize,
@@ -466,17 +790,23 @@ pub struct GameOverRoot;
 #[derive(Component)]
 struct PauseMenuRoot;
 
-fn setup_game_over_screen(mut commands: Commands, board_res: Res<BoardResource>) {
+fn setup_game_over_screen(
+    mut commands: Commands,
+    board_res: Res<BoardResource>,
+    config: Res<GameConfig>,
+) {
     let score = board_res.0.end_of_game().unwrap();
     let black_score = score.player1();
     let white_score = score.player2();
-
-    let result_text = if black_score > white_score {
-        "Victoire !"
-    } else if white_score > black_score {
-        "Defaite..."
-    } else {
-        "Match Nul !"
+    let seats = &config.seats;
+    let single_human = seats.has_human() && seats.controller(Black) != seats.controller(White);
+
+    let (result_text, celebrate) = match score.winner() {
+        None => ("Match Nul !", false),
+        Some(Black) if !single_human => ("Victoire des Noirs !", true),
+        Some(White) if !single_human => ("Victoire des Blancs !", true),
+        Some(color) if seats.controller(color) == Controller::Human => ("Victoire !", true),
+        Some(_) => ("Defaite...", false),
     };
 
     let score_text = format!("Noir: {} - Blanc: {}", black_score, white_score);
@@ -513,7 +843,7 @@ fn setup_game_over_screen(mut commands: Commands, board_res: Res<BoardResource>)
             ));
         });
 
-    if black_score > white_score {
+    if celebrate {
         // Déclencher le feu d'artifice
         for i in 0..15 {
             commands.spawn((
@@ -605,6 +935,15 @@ fn toggle_pause(mut next_state: ResMut<NextState<GameState>>) {
 #[derive(Component)]
 struct ResumeButton;
 
+#[derive(Component)]
+struct RestartButton;
+
+#[derive(Component)]
+struct SaveButton;
+
+#[derive(Component)]
+struct LoadButton;
+
 #[derive(Component)]
 struct QuitButton;
 
@@ -636,55 +975,58 @@ fn setup_pause_menu(mut commands: Commands) {
                 },
             ));
 
-            parent
-                .spawn((
-                    ResumeButton,
-                    Button,
-                    Node {
-                        width: Val::Px(200.0),
-                        height: Val::Px(60.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        margin: UiRect::all(Val::Px(10.0)),
-                        ..default()
-                    },
-                    BackgroundColor(Color::srgb(0.2, 0.6, 0.2)),
-                ))
-                .with_children(|parent| {
-                    parent.spawn((
-                        Text::new("Redémarrer"),
-                        TextFont {
-                            font_size: 30.0,
-                            ..default()
-                        },
-                        TextColor(Color::WHITE),
-                    ));
-                });
-
-            parent
-                .spawn((
-                    QuitButton,
-                    Button,
-                    Node {
-                        width: Val::Px(200.0),
-                        height: Val::Px(60.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        margin: UiRect::all(Val::Px(10.0)),
-                        ..default()
-                    },
-                    BackgroundColor(Color::srgb(0.6, 0.2, 0.2)),
-                ))
-                .with_children(|parent| {
-                    parent.spawn((
-                        Text::new("Quitter"),
-                        TextFont {
-                            font_size: 30.0,
-                            ..default()
-                        },
-                        TextColor(Color::WHITE),
-                    ));
-                });
+            spawn_pause_button(
+                parent,
+                ResumeButton,
+                "Reprendre",
+                Color::srgb(0.2, 0.6, 0.2),
+            );
+            spawn_pause_button(
+                parent,
+                RestartButton,
+                "Redémarrer",
+                Color::srgb(0.6, 0.5, 0.2),
+            );
+            spawn_pause_button(
+                parent,
+                SaveButton,
+                "Sauvegarder",
+                Color::srgb(0.2, 0.4, 0.6),
+            );
+            spawn_pause_button(parent, LoadButton, "Charger", Color::srgb(0.2, 0.4, 0.6));
+            spawn_pause_button(parent, QuitButton, "Quitter", Color::srgb(0.6, 0.2, 0.2));
+        });
+}
+
+fn spawn_pause_button(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    marker: impl Component,
+    label: &str,
+    color: Color,
+) {
+    parent
+        .spawn((
+            marker,
+            Button,
+            Node {
+                width: Val::Px(200.0),
+                height: Val::Px(60.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(color),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
         });
 }
 
@@ -708,6 +1050,96 @@ fn handle_pause_resume(
     }
 }
 
+/// Recommence la partie avec les mêmes joueurs. Le plateau est redessiné et le premier tour
+/// relancé en entrant dans `InGame`.
+fn handle_pause_restart(
+    mut interaction_query: Query<&Interaction, (Changed<Interaction>, With<RestartButton>)>,
+    use_case: Res<UseCaseResource>,
+    start_position: Res<StartPosition>,
+    config: Res<GameConfig>,
+    mut game_res: ResMut<BoardResource>,
+    mut record: ResMut<RecordResource>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for interaction in &mut interaction_query {
+        if *interaction == Interaction::Pressed {
+            let board = new_board(&use_case.0, &start_position);
+            record.0 = GameRecord::new(&board, config.seats);
+            game_res.0 = board;
+            next_state.set(InGame);
+        }
+    }
+}
+
+fn handle_pause_save(
+    mut interaction_query: Query<&Interaction, (Changed<Interaction>, With<SaveButton>)>,
+    mut commands: Commands,
+    game_res: Res<BoardResource>,
+    mut record: ResMut<RecordResource>,
+    repository: Res<RepositoryResource>,
+) {
+    for interaction in &mut interaction_query {
+        if *interaction == Interaction::Pressed {
+            record.0.update(&game_res.0);
+            match repository.0.save(SAVE_ID, &record.0) {
+                Ok(()) => spawn_notice(&mut commands, "Partie sauvegardée"),
+                Err(error) => {
+                    warn!("Sauvegarde impossible : {}", error);
+                    spawn_notice(&mut commands, "Sauvegarde impossible");
+                }
+            }
+        }
+    }
+}
+
+fn handle_pause_load(
+    mut interaction_query: Query<&Interaction, (Changed<Interaction>, With<LoadButton>)>,
+    mut commands: Commands,
+    repository: Res<RepositoryResource>,
+) {
+    for interaction in &mut interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let loaded = repository
+            .0
+            .load(SAVE_ID)
+            .map_err(|error| error.to_string())
+            .and_then(|record| {
+                if has_external_seat(&record.seats()) {
+                    return Err("joueur externe non pris en charge".to_string());
+                }
+                let board = record.replay().map_err(|error| error.to_string())?;
+                Ok(GameLoaded { record, board })
+            });
+        match loaded {
+            Ok(game_loaded) => commands.trigger(game_loaded),
+            Err(error) => {
+                warn!("Chargement impossible : {}", error);
+                spawn_notice(&mut commands, "Chargement impossible");
+            }
+        }
+    }
+}
+
+/// Reprend la partie sauvegardée avec ses joueurs et le niveau de l'IA. Le plateau est
+/// redessiné en entrant dans `InGame`.
+fn restore_game(
+    game_loaded: On<GameLoaded>,
+    mut game_res: ResMut<BoardResource>,
+    mut record: ResMut<RecordResource>,
+    mut use_case: ResMut<UseCaseResource>,
+    mut config: ResMut<GameConfig>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    config.seats = game_loaded.record.seats();
+    use_case.0.set_seats(&config.seats);
+    game_res.0 = game_loaded.board.clone();
+    record.0 = game_loaded.record.clone();
+    let over = game_res.0.end_of_game().is_some();
+    next_state.set(if over { EndGame } else { InGame });
+}
+
 fn handle_pause_quit(
     mut interaction_query: Query<
         &Interaction,
@@ -2,6 +2,11 @@ use crate::{GameConfig, GameState};
 use bevy::app::AppExit;
 use bevy::ecs::relationship::RelatedSpawnerCommands;
 use bevy::prelude::*;
+use reversi_core::application::difficulty::Difficulty;
+use reversi_core::application::seats::Controller;
+use reversi_core::domain::board::ColorPiece;
+use reversi_core::domain::board::ColorPiece::{Black, White};
+use reversi_core::presentation::labels::{color_name, difficulty_label};
 
 pub struct MenuPlugin;
 
@@ -27,6 +32,7 @@ enum MenuButtonAction {
 #[derive(Component)]
 enum ConfigButtonAction {
     ToggleIndicators,
+    ToggleSeat(ColorPiece),
     Back,
 }
 
@@ -174,6 +180,19 @@ fn setup_config_menu(mut commands: Commands, config: Res<GameConfig>) {
             };
             spawn_config_button(parent, indicator_text, ConfigButtonAction::ToggleIndicators);
 
+            for color in [Black, White] {
+                let controller = match config.seats.controller(color) {
+                    Controller::Human => "Humain".to_string(),
+                    Controller::Ai(difficulty) => format!("IA {}", difficulty_label(difficulty)),
+                    Controller::External => "Externe".to_string(),
+                };
+                spawn_config_button(
+                    parent,
+                    &format!("{}: {}", color_name(color), controller),
+                    ConfigButtonAction::ToggleSeat(color),
+                );
+            }
+
             spawn_config_button(parent, "Retour", ConfigButtonAction::Back);
         });
 }
@@ -187,7 +206,7 @@ fn spawn_config_button(
         .spawn((
             Button,
             Node {
-                width: Val::Px(200.0),
+                width: Val::Px(440.0),
                 height: Val::Px(65.0),
                 border: UiRect::all(Val::Px(5.0)),
                 justify_content: JustifyContent::Center,
@@ -227,6 +246,18 @@ fn config_action(
                         config.show_playable_indicators = !config.show_playable_indicators;
                         next_state.set(GameState::Config);
                     }
+                    ConfigButtonAction::ToggleSeat(color) => {
+                        // Humain, puis l'IA de chaque niveau, puis de nouveau humain
+                        let controller = match config.seats.controller(*color) {
+                            Controller::Ai(Difficulty::Expert) => Controller::Human,
+                            Controller::Ai(difficulty) => Controller::Ai(difficulty.next()),
+                            Controller::Human | Controller::External => {
+                                Controller::Ai(Difficulty::Beginner)
+                            }
+                        };
+                        config.seats.set_controller(*color, controller);
+                        next_state.set(GameState::Config);
+                    }
                     ConfigButtonAction::Back => {
                         next_state.set(GameState::Menu);
                     }
@@ -1,20 +1,46 @@
 use crate::application::move_use_case::MoveUseCase;
-use crate::domain::board::Board;
+use crate::domain::board::{Board, ColorPiece};
+use crate::domain::moves::MoveError;
+use crate::domain::search::{SearchControl, SearchStrategy};
+use std::sync::Arc;
 
 pub struct AIMoveUseCase {
     move_use_case: Box<dyn MoveUseCase>,
+    /// Strategies of Black and White, so that two AIs of different levels can play each other.
+    strategies: [Arc<dyn SearchStrategy>; 2],
+}
+
+/// Search of the AI detached from the game, so that it can run on another thread.
+pub struct PendingSearch {
+    strategy: Arc<dyn SearchStrategy>,
+    board: Board,
+    control: SearchControl,
+}
+
+impl PendingSearch {
+    /// Position chosen by the AI, to be played with `AIMoveUseCase::play`.
+    pub fn run(self) -> Option<(usize, usize)> {
+        self.strategy
+            .select_move_controlled(&self.board, &self.control)
+    }
 }
 
 pub struct SelectedMove {
     position: (usize, usize),
     pieces_to_flip: Vec<(usize, usize)>,
+    pass: Option<ColorPiece>,
 }
 
 impl SelectedMove {
-    pub fn new(position: (usize, usize), pieces_to_flip: Vec<(usize, usize)>) -> Self {
+    pub fn new(
+        position: (usize, usize),
+        pieces_to_flip: Vec<(usize, usize)>,
+        pass: Option<ColorPiece>,
+    ) -> Self {
         Self {
             position,
             pieces_to_flip,
+            pass,
         }
     }
 
@@ -25,27 +51,74 @@ impl SelectedMove {
     pub fn pieces_to_flip(self) -> Vec<(usize, usize)> {
         self.pieces_to_flip
     }
+
+    /// Color of the player who has to pass after this move, if any.
+    pub fn pass(&self) -> Option<ColorPiece> {
+        self.pass
+    }
 }
 
 impl AIMoveUseCase {
-    pub fn new(move_use_case: Box<dyn MoveUseCase>) -> Self {
-        Self { move_use_case }
+    pub fn new(move_use_case: Box<dyn MoveUseCase>, strategy: Box<dyn SearchStrategy>) -> Self {
+        let strategy: Arc<dyn SearchStrategy> = Arc::from(strategy);
+        Self {
+            move_use_case,
+            strategies: [Arc::clone(&strategy), strategy],
+        }
+    }
+
+    /// Same strategy for both colors.
+    pub fn set_strategy(&mut self, strategy: Box<dyn SearchStrategy>) {
+        let strategy: Arc<dyn SearchStrategy> = Arc::from(strategy);
+        self.strategies = [Arc::clone(&strategy), strategy];
+    }
+
+    pub fn set_color_strategy(&mut self, color: ColorPiece, strategy: Box<dyn SearchStrategy>) {
+        self.strategies[Self::index(color)] = Arc::from(strategy);
+    }
+
+    fn index(color: ColorPiece) -> usize {
+        match color {
+            ColorPiece::Black => 0,
+            ColorPiece::White => 1,
+        }
     }
 
-    pub fn execute(&self, board: &mut Board) -> Option<SelectedMove> {
-        let available_moves = board.available_positions(board.current_player());
-        if available_moves.is_empty() {
-            return None;
+    /// Strategy of the player who has to move on this board.
+    fn strategy(&self, board: &Board) -> &Arc<dyn SearchStrategy> {
+        &self.strategies[Self::index(board.current_player().color())]
+    }
+
+    /// Move of the AI, `None` when it has no move to play.
+    pub fn execute(&self, board: &mut Board) -> Result<Option<SelectedMove>, MoveError> {
+        match self.strategy(board).select_move(board) {
+            Some(position_choose) => self.play(board, position_choose).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Prepares a search on a copy of the board, followed and cancelled through `control`.
+    pub fn search(&self, board: &Board, control: SearchControl) -> PendingSearch {
+        PendingSearch {
+            strategy: Arc::clone(self.strategy(board)),
+            board: board.clone(),
+            control,
         }
+    }
 
-        let num = fastrand::usize(0..available_moves.len());
-        let position_choose = available_moves[num];
+    /// Plays the position found by a search, which may no longer be legal on this board.
+    pub fn play(
+        &self,
+        board: &mut Board,
+        position_choose: (usize, usize),
+    ) -> Result<SelectedMove, MoveError> {
         let move_result = self
             .move_use_case
             .execute(board, position_choose.0, position_choose.1);
-        move_result.map(|moves| SelectedMove {
+        move_result.map(|outcome| SelectedMove {
             position: position_choose,
-            pieces_to_flip: moves,
+            pass: outcome.pass(),
+            pieces_to_flip: outcome.into_flipped(),
         })
     }
 }
@@ -53,10 +126,12 @@ impl AIMoveUseCase {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::move_use_case::MockMoveUseCase;
+    use crate::application::move_use_case::{MockMoveUseCase, MoveUseCaseImpl};
     use crate::domain::board::Case;
     use crate::domain::board::Case::Empty;
     use crate::domain::board::ColorPiece::{Black, White};
+    use crate::domain::moves::MoveOutcome;
+    use crate::domain::search::{MctsStrategy, MockSearchStrategy, RandomStrategy};
     use mockall::predicate;
 
     #[test]
@@ -70,11 +145,132 @@ mod tests {
         move_use_case_mock
             .expect_execute()
             .with(predicate::always(), predicate::eq(0), predicate::eq(3))
-            .return_const(vec![]);
+            .return_const(Ok(MoveOutcome::new(vec![], None)));
+
+        let ai_move_use_case =
+            AIMoveUseCase::new(Box::new(move_use_case_mock), Box::new(RandomStrategy {}));
+
+        // When / Then
+        assert!(ai_move_use_case.execute(&mut board).is_ok());
+    }
+
+    #[test]
+    fn should_play_move_selected_by_strategy() {
+        // Given
+        let mut board = Board::default();
+        let mut strategy_mock = MockSearchStrategy::new();
+        strategy_mock
+            .expect_select_move()
+            .return_const(Some((5, 4)));
+        let mut move_use_case_mock = MockMoveUseCase::new();
+        move_use_case_mock
+            .expect_execute()
+            .with(predicate::always(), predicate::eq(5), predicate::eq(4))
+            .return_const(Ok(MoveOutcome::new(vec![(4, 4)], None)));
 
-        let ai_move_use_case = AIMoveUseCase::new(Box::new(move_use_case_mock));
+        let ai_move_use_case =
+            AIMoveUseCase::new(Box::new(move_use_case_mock), Box::new(strategy_mock));
+
+        // When
+        let result = ai_move_use_case.execute(&mut board);
+
+        // Then
+        let selected_move = result
+            .expect("Move should be legal")
+            .expect("A move should be selected");
+        assert_eq!(selected_move.position(), (5, 4));
+        assert_eq!(selected_move.pieces_to_flip(), vec![(4, 4)]);
+    }
+
+    #[test]
+    fn should_not_play_when_strategy_finds_no_move() {
+        // Given
+        let mut board = Board::default();
+        let mut strategy_mock = MockSearchStrategy::new();
+        strategy_mock.expect_select_move().return_const(None);
+        let mut move_use_case_mock = MockMoveUseCase::new();
+        move_use_case_mock.expect_execute().never();
+
+        let ai_move_use_case =
+            AIMoveUseCase::new(Box::new(move_use_case_mock), Box::new(strategy_mock));
+
+        // When / Then
+        assert!(matches!(ai_move_use_case.execute(&mut board), Ok(None)));
+    }
+
+    #[test]
+    fn should_use_strategy_of_the_color_to_move() {
+        // Given
+        let mut board = Board::default();
+        let mut white_mock = MockSearchStrategy::new();
+        white_mock.expect_select_move().never();
+        let mut black_mock = MockSearchStrategy::new();
+        black_mock.expect_select_move().return_const(Some((5, 4)));
+        let mut ai_move_use_case =
+            AIMoveUseCase::new(Box::new(MoveUseCaseImpl {}), Box::new(white_mock));
+        ai_move_use_case.set_color_strategy(Black, Box::new(black_mock));
+
+        // When
+        let result = ai_move_use_case.execute(&mut board);
+
+        // Then
+        assert!(matches!(result, Ok(Some(selected_move)) if selected_move.position() == (5, 4)));
+    }
+
+    #[test]
+    fn should_run_search_with_its_control() {
+        // Given
+        let board = Board::default();
+        let mut strategy_mock = MockSearchStrategy::new();
+        strategy_mock
+            .expect_select_move_controlled()
+            .withf(|_, control| control.is_cancelled())
+            .return_const(Some((5, 4)));
+        let move_use_case_mock = MockMoveUseCase::new();
+        let ai_move_use_case =
+            AIMoveUseCase::new(Box::new(move_use_case_mock), Box::new(strategy_mock));
+        let control = SearchControl::default();
+        control.cancel();
+
+        // When
+        let result = ai_move_use_case.search(&board, control).run();
+
+        // Then
+        assert_eq!(result, Some((5, 4)));
+    }
+
+    #[test]
+    fn should_not_play_illegal_position() {
+        // Given
+        let mut board = Board::default();
+        let ai_move_use_case =
+            AIMoveUseCase::new(Box::new(MoveUseCaseImpl {}), Box::new(RandomStrategy {}));
 
         // When / Then
-        ai_move_use_case.execute(&mut board);
+        assert_eq!(
+            ai_move_use_case.play(&mut board, (0, 0)).err(),
+            Some(MoveError::NoFlip)
+        );
+        assert!(ai_move_use_case.play(&mut board, (5, 4)).is_ok());
+    }
+
+    #[test]
+    fn should_play_move_chosen_by_mcts() {
+        // Given
+        let mut board = Board::default();
+        let ai_move_use_case = AIMoveUseCase::new(
+            Box::new(MoveUseCaseImpl {}),
+            Box::new(MctsStrategy::new(200).with_seed(5)),
+        );
+
+        // When
+        let result = ai_move_use_case.execute(&mut board);
+
+        // Then
+        let selected_move = result
+            .expect("Move should be legal")
+            .expect("A move should be selected");
+        assert_eq!(selected_move.pieces_to_flip().len(), 1);
+        assert!(board.player2());
     }
 }
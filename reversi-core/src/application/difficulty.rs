@@ -0,0 +1,180 @@
+use crate::domain::board::Board;
+use crate::domain::book::OpeningBook;
+use crate::domain::search::{
+    BookStrategy, Budget, EndgameSolver, MistakeStrategy, NegamaxStrategy, RandomStrategy,
+    SearchStrategy,
+};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Strength of the AI.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Difficulty {
+    Beginner,
+    #[default]
+    Intermediate,
+    Advanced,
+    Expert,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Beginner,
+        Difficulty::Intermediate,
+        Difficulty::Advanced,
+        Difficulty::Expert,
+    ];
+
+    /// Maximum number of moves the AI looks ahead, if its time budget allows it.
+    pub fn depth(self) -> u8 {
+        match self {
+            Difficulty::Beginner => 1,
+            Difficulty::Intermediate => 3,
+            Difficulty::Advanced => 6,
+            Difficulty::Expert => 20,
+        }
+    }
+
+    /// Number of empty cells from which the AI plays the end of the game perfectly.
+    pub fn endgame_empties(self) -> u32 {
+        match self {
+            Difficulty::Beginner => 0,
+            Difficulty::Intermediate => 6,
+            Difficulty::Advanced => 10,
+            Difficulty::Expert => 14,
+        }
+    }
+
+    /// Time the AI may think for each move.
+    pub fn time_budget(self) -> Duration {
+        match self {
+            Difficulty::Beginner => Duration::from_millis(200),
+            Difficulty::Intermediate => Duration::from_millis(500),
+            Difficulty::Advanced => Duration::from_secs(1),
+            Difficulty::Expert => Duration::from_secs(2),
+        }
+    }
+
+    /// Probability that the AI plays a random move instead of the best one it found.
+    pub fn mistake_rate(self) -> f32 {
+        match self {
+            Difficulty::Beginner => 0.3,
+            Difficulty::Intermediate => 0.1,
+            Difficulty::Advanced | Difficulty::Expert => 0.0,
+        }
+    }
+
+    /// Whether the AI plays the openings of the builtin book.
+    pub fn opening_book(self) -> bool {
+        matches!(self, Difficulty::Advanced | Difficulty::Expert)
+    }
+
+    pub fn strategy(self) -> Box<dyn SearchStrategy> {
+        let search = Box::new(self.search_with_budget(Budget::Time(self.time_budget())));
+        if self.mistake_rate() > 0.0 {
+            Box::new(MistakeStrategy::new(search, self.mistake_rate()))
+        } else if self.opening_book() {
+            Box::new(BookStrategy::new(OpeningBook::builtin(), search))
+        } else {
+            search
+        }
+    }
+
+    /// Search of the level within `budget` instead of its time, without its mistakes nor its
+    /// book, for the frontends which spread a search over several calls.
+    pub fn search_with_budget(self, budget: Budget) -> NegamaxStrategy {
+        NegamaxStrategy::new(self.depth())
+            .with_budget(budget)
+            .with_endgame_solver(EndgameSolver::new(self.endgame_empties()))
+    }
+
+    /// Move the level plays without searching, drawn once per move: a random one when it
+    /// makes a mistake, or one of `book` when it follows it.
+    pub fn unsearched_move(self, board: &Board, book: &OpeningBook) -> Option<(usize, usize)> {
+        if fastrand::f32() < self.mistake_rate() {
+            RandomStrategy {}.select_move(board)
+        } else if self.opening_book() {
+            book.choose(board, &mut fastrand::Rng::new())
+        } else {
+            None
+        }
+    }
+
+    /// Following level, back to the first one after the last.
+    pub fn next(self) -> Difficulty {
+        let index = Difficulty::ALL.iter().position(|d| *d == self).unwrap_or(0);
+        Difficulty::ALL[(index + 1) % Difficulty::ALL.len()]
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Difficulty::Beginner => "beginner",
+            Difficulty::Intermediate => "intermediate",
+            Difficulty::Advanced => "advanced",
+            Difficulty::Expert => "expert",
+        })
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown difficulty \"{}\"", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::book::BookMove;
+
+    #[test]
+    fn should_get_stronger_with_each_level() {
+        for pair in Difficulty::ALL.windows(2) {
+            assert!(pair[0].depth() <= pair[1].depth());
+            assert!(pair[0].endgame_empties() <= pair[1].endgame_empties());
+            assert!(pair[0].time_budget() <= pair[1].time_budget());
+            assert!(pair[0].mistake_rate() >= pair[1].mistake_rate());
+            assert!(pair[0].opening_book() <= pair[1].opening_book());
+        }
+    }
+
+    #[test]
+    fn should_draw_book_moves_only_for_levels_following_the_book() {
+        // Given
+        let board = Board::default();
+        let book = OpeningBook::builtin();
+
+        // When
+        let expert = Difficulty::Expert.unsearched_move(&board, &book);
+        let out_of_book = Difficulty::Expert.unsearched_move(&board, &OpeningBook::default());
+
+        // Then
+        let positions: Vec<_> = book.moves(&board).iter().map(BookMove::position).collect();
+        assert!(expert.is_some_and(|position| positions.contains(&position)));
+        assert_eq!(out_of_book, None);
+    }
+
+    #[test]
+    fn should_cycle_through_levels() {
+        assert_eq!(Difficulty::Beginner.next(), Difficulty::Intermediate);
+        assert_eq!(Difficulty::Expert.next(), Difficulty::Beginner);
+    }
+
+    #[test]
+    fn should_parse_displayed_levels() {
+        for difficulty in Difficulty::ALL {
+            assert_eq!(difficulty.to_string().parse(), Ok(difficulty));
+        }
+        assert_eq!("EXPERT".parse(), Ok(Difficulty::Expert));
+        assert!("impossible".parse::<Difficulty>().is_err());
+    }
+}
@@ -0,0 +1,213 @@
+use crate::application::seats::{Controller, Seats};
+use crate::domain::board::ColorPiece::{Black, White};
+use crate::domain::board::{Board, ColorPiece, Score};
+use crate::domain::moves::Move;
+use crate::domain::notation::NotationError;
+use crate::domain::position::PositionError;
+use std::fmt;
+use web_time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, PartialEq)]
+pub enum RecordError {
+    /// The start position cannot be read.
+    InvalidPosition(PositionError),
+    /// One of the moves cannot be played from the start position.
+    InvalidMoves(NotationError),
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordError::InvalidPosition(error) => write!(f, "invalid start position: {}", error),
+            RecordError::InvalidMoves(error) => write!(f, "invalid moves: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for RecordError {}
+
+/// Player of one color.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerRecord {
+    /// Empty when the frontend does not ask for names.
+    pub name: String,
+    pub controller: Controller,
+}
+
+/// A game with everything needed to replay it, saved while it is played or once it is over.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(test, derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameRecord {
+    pub black: PlayerRecord,
+    pub white: PlayerRecord,
+    /// Position the game started from, as written by `Board`'s `Display`.
+    pub start: String,
+    /// Moves of both players, including the passes.
+    pub moves: Vec<Move>,
+    /// Seconds since the Unix epoch.
+    pub started_at: u64,
+    pub finished_at: Option<u64>,
+    pub score: Option<Score>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+impl GameRecord {
+    /// Record of a game starting now, with the moves already played on the board.
+    pub fn new(board: &Board, seats: Seats) -> Self {
+        let player = |color| PlayerRecord {
+            name: String::new(),
+            controller: seats.controller(color),
+        };
+        let mut start = board.clone();
+        while start.undo().is_some() {}
+        let mut record = Self {
+            black: player(Black),
+            white: player(White),
+            start: start.to_string(),
+            moves: Vec::new(),
+            started_at: now(),
+            finished_at: None,
+            score: None,
+        };
+        record.update(board);
+        record
+    }
+
+    /// Takes the moves of the board, which must have been played from the start position, and
+    /// the time the game ended if it is over.
+    pub fn update(&mut self, board: &Board) {
+        self.moves = board.history();
+        self.score = board.end_of_game();
+        match self.score {
+            Some(_) => self.finished_at = self.finished_at.or_else(|| Some(now())),
+            None => self.finished_at = None,
+        }
+    }
+
+    pub fn player(&self, color: ColorPiece) -> &PlayerRecord {
+        match color {
+            Black => &self.black,
+            White => &self.white,
+        }
+    }
+
+    pub fn seats(&self) -> Seats {
+        Seats::new(self.black.controller, self.white.controller)
+    }
+
+    /// Board after all the moves, their history kept so they can be taken back.
+    pub fn replay(&self) -> Result<Board, RecordError> {
+        let mut board: Board = self.start.parse().map_err(RecordError::InvalidPosition)?;
+        board
+            .play_moves(&self.moves)
+            .map_err(RecordError::InvalidMoves)?;
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::difficulty::Difficulty;
+    use crate::domain::moves::MoveError;
+
+    #[test]
+    fn should_record_moves_and_settings() {
+        // Given
+        let board = Board::from_transcript("f5d6c3").expect("Transcript should be legal");
+
+        // When
+        let seats = Seats::new(Controller::Human, Controller::Ai(Difficulty::Expert));
+        let record = GameRecord::new(&board, seats);
+
+        // Then
+        assert_eq!(record.start, Board::default().to_string());
+        assert_eq!(record.moves.len(), 3);
+        assert_eq!(
+            record.player(White).controller,
+            Controller::Ai(Difficulty::Expert)
+        );
+        assert_eq!(record.seats(), seats);
+        assert_eq!(record.score, None);
+        assert_eq!(record.finished_at, None);
+    }
+
+    #[test]
+    fn should_replay_from_start_position_with_passes() {
+        // Given: white has to pass after black takes a1, black can still take c8
+        let position = format!("-O*-----{}*O------ *", "-".repeat(48));
+        let mut board: Board = position.parse().expect("Position should be valid");
+        let mut record = GameRecord::new(&board, Seats::default());
+        board.play(Move::Play(0, 0)).expect("Move should be legal");
+
+        // When
+        record.update(&board);
+
+        // Then
+        let replayed = record.replay().expect("Record should be valid");
+        assert_eq!(replayed.to_string(), board.to_string());
+        assert_eq!(replayed.history(), vec![Move::Play(0, 0), Move::Pass]);
+    }
+
+    #[test]
+    fn should_keep_score_and_end_time_of_finished_game() {
+        // Given: black takes a1 and wins every disc
+        let mut board: Board = format!("-O{} *", "*".repeat(62))
+            .parse()
+            .expect("Position should be valid");
+        let mut record = GameRecord::new(&board, Seats::default());
+        board.play(Move::Play(0, 0)).expect("Move should be legal");
+
+        // When
+        record.update(&board);
+
+        // Then
+        let score = record.score.expect("Game should be over");
+        assert_eq!((score.player1(), score.player2()), (64, 0));
+        assert!(
+            record
+                .finished_at
+                .is_some_and(|end| end >= record.started_at)
+        );
+    }
+
+    #[test]
+    fn should_report_illegal_moves() {
+        // Given
+        let mut record = GameRecord::new(&Board::default(), Seats::default());
+        record.moves = vec![Move::Play(0, 0)];
+
+        // When / Then
+        assert_eq!(
+            record.replay().err(),
+            Some(RecordError::InvalidMoves(NotationError::IllegalMove {
+                index: 0,
+                mv: Move::Play(0, 0),
+                reason: MoveError::NoFlip
+            }))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_round_trip_through_json() {
+        // Given
+        let board = Board::from_transcript("f5d6c3").expect("Transcript should be legal");
+        let mut record = GameRecord::new(&board, Seats::default());
+        record.black.name = "Alice".to_string();
+
+        // When
+        let json = serde_json::to_string(&record).expect("Record should be serialized");
+        let read: GameRecord = serde_json::from_str(&json).expect("JSON should be valid");
+
+        // Then
+        assert_eq!(read, record);
+    }
+}
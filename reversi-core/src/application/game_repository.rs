@@ -0,0 +1,164 @@
+use crate::application::game_record::GameRecord;
+#[cfg(test)]
+use mockall::automock;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+
+#[derive(Debug, PartialEq)]
+pub enum RepositoryError {
+    /// Ids are made of ASCII letters, digits, `-` and `_`, so that every storage accepts them.
+    InvalidId(String),
+    NotFound(String),
+    /// The stored game cannot be read back.
+    Corrupted {
+        id: String,
+        message: String,
+    },
+    /// The storage itself failed.
+    Storage(String),
+}
+
+impl fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepositoryError::InvalidId(id) => write!(f, "invalid game id \"{}\"", id),
+            RepositoryError::NotFound(id) => write!(f, "no game \"{}\"", id),
+            RepositoryError::Corrupted { id, message } => {
+                write!(f, "game \"{}\" cannot be read: {}", id, message)
+            }
+            RepositoryError::Storage(message) => write!(f, "storage error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for RepositoryError {}
+
+/// Checks an id before it reaches the storage.
+pub fn check_id(id: &str) -> Result<(), RepositoryError> {
+    let valid = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(RepositoryError::InvalidId(id.to_string()))
+    }
+}
+
+/// Storage of game records, shared by the frontends and the tools.
+#[cfg_attr(test, automock)]
+pub trait GameRepository: Send + Sync {
+    /// Stores the game, replacing the one saved with the same id.
+    fn save(&self, id: &str, record: &GameRecord) -> Result<(), RepositoryError>;
+    fn load(&self, id: &str) -> Result<GameRecord, RepositoryError>;
+    /// Ids of the saved games, sorted.
+    fn list(&self) -> Result<Vec<String>, RepositoryError>;
+    fn delete(&self, id: &str) -> Result<(), RepositoryError>;
+}
+
+/// Games kept in memory, lost when the program stops.
+#[derive(Default)]
+pub struct InMemoryGameRepository {
+    records: Mutex<BTreeMap<String, GameRecord>>,
+}
+
+impl InMemoryGameRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn records(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, GameRecord>> {
+        // A panic while holding the lock cannot leave the map half updated
+        self.records
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl GameRepository for InMemoryGameRepository {
+    fn save(&self, id: &str, record: &GameRecord) -> Result<(), RepositoryError> {
+        check_id(id)?;
+        self.records().insert(id.to_string(), record.clone());
+        Ok(())
+    }
+
+    fn load(&self, id: &str) -> Result<GameRecord, RepositoryError> {
+        check_id(id)?;
+        self.records()
+            .get(id)
+            .cloned()
+            .ok_or_else(|| RepositoryError::NotFound(id.to_string()))
+    }
+
+    fn list(&self) -> Result<Vec<String>, RepositoryError> {
+        Ok(self.records().keys().cloned().collect())
+    }
+
+    fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        check_id(id)?;
+        self.records()
+            .remove(id)
+            .map(|_| ())
+            .ok_or_else(|| RepositoryError::NotFound(id.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::seats::Seats;
+    use crate::domain::board::Board;
+
+    fn record(transcript: &str) -> GameRecord {
+        let board = Board::from_transcript(transcript).expect("Transcript should be legal");
+        GameRecord::new(&board, Seats::default())
+    }
+
+    #[test]
+    fn should_save_load_list_and_delete_games() {
+        // Given
+        let repository = InMemoryGameRepository::new();
+        let first = record("f5d6");
+
+        // When
+        repository
+            .save("b-game", &record("f5"))
+            .expect("Game should be saved");
+        repository
+            .save("a_game", &first)
+            .expect("Game should be saved");
+
+        // Then
+        assert_eq!(
+            repository.list(),
+            Ok(vec!["a_game".to_string(), "b-game".to_string()])
+        );
+        assert!(
+            repository
+                .load("a_game")
+                .is_ok_and(|loaded| loaded == first)
+        );
+        assert_eq!(repository.delete("a_game"), Ok(()));
+        assert_eq!(
+            repository.load("a_game").err(),
+            Some(RepositoryError::NotFound("a_game".to_string()))
+        );
+        assert_eq!(
+            repository.delete("a_game"),
+            Err(RepositoryError::NotFound("a_game".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_reject_ids_unsafe_for_storage() {
+        let repository = InMemoryGameRepository::new();
+        for id in ["", "../game", "game.json", "é"] {
+            assert_eq!(
+                repository.save(id, &record("f5")),
+                Err(RepositoryError::InvalidId(id.to_string()))
+            );
+        }
+    }
+}
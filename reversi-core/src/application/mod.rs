@@ -5,3 +5,7 @@ pub mod player_move_use_case;
 pub mod ai_move_use_case;
 pub mod evaluate_game_end_use_case;
 mod move_use_case;
+pub mod seats;
+pub mod difficulty;
+pub mod game_record;
+pub mod game_repository;
@@ -1,17 +1,18 @@
 use crate::domain::board::Board;
+use crate::domain::moves::{Move, MoveError, MoveOutcome};
 
 #[cfg(test)]
 use mockall::automock;
 
 #[cfg_attr(test, automock)]
 pub trait MoveUseCase: Send + Sync {
-    fn execute(&self, board: &mut Board, x: usize, y: usize) -> Option<Vec<(usize, usize)>>;
+    fn execute(&self, board: &mut Board, x: usize, y: usize) -> Result<MoveOutcome, MoveError>;
 }
 
 pub struct MoveUseCaseImpl {}
 
 impl MoveUseCase for MoveUseCaseImpl {
-    fn execute(&self, board: &mut Board, x: usize, y: usize) -> Option<Vec<(usize, usize)>> {
-        board.place(x, y)
+    fn execute(&self, board: &mut Board, x: usize, y: usize) -> Result<MoveOutcome, MoveError> {
+        board.play(Move::Play(x, y))
     }
 }
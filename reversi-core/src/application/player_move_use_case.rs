@@ -1,5 +1,6 @@
 use crate::application::move_use_case::MoveUseCase;
 use crate::domain::board::Board;
+use crate::domain::moves::{MoveError, MoveOutcome};
 
 pub struct PlayerMoveUseCase {
     move_use_case: Box<dyn MoveUseCase>,
@@ -10,7 +11,7 @@ impl PlayerMoveUseCase {
         Self { move_use_case }
     }
 
-    pub fn execute(&self, board: &mut Board, x: usize, y: usize) -> Option<Vec<(usize, usize)>> {
+    pub fn execute(&self, board: &mut Board, x: usize, y: usize) -> Result<MoveOutcome, MoveError> {
         self.move_use_case.execute(board, x, y)
     }
 }
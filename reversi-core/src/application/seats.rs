@@ -0,0 +1,152 @@
+use crate::application::difficulty::Difficulty;
+use crate::domain::board::ColorPiece::{Black, White};
+use crate::domain::board::{Board, ColorPiece};
+use std::fmt;
+use std::str::FromStr;
+
+/// Who chooses the moves of one color.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Controller {
+    Human,
+    Ai(Difficulty),
+    /// Moves come from outside the application (another program, the network...).
+    External,
+}
+
+impl Controller {
+    pub fn is_ai(self) -> bool {
+        matches!(self, Controller::Ai(_))
+    }
+
+    /// Reads `human`, `external`, `ai:<level>` or `ai`, which plays at `level`.
+    pub fn parse_with_level(s: &str, level: Difficulty) -> Result<Self, String> {
+        let lowercase = s.to_ascii_lowercase();
+        match lowercase.split_once(':') {
+            Some(("ai", level)) => level.parse().map(Controller::Ai),
+            Some(_) => Err(format!("unknown controller \"{}\"", s)),
+            None => match lowercase.as_str() {
+                "human" => Ok(Controller::Human),
+                "ai" => Ok(Controller::Ai(level)),
+                "external" => Ok(Controller::External),
+                _ => Err(format!("unknown controller \"{}\"", s)),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Controller {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Controller::Human => f.write_str("human"),
+            Controller::Ai(difficulty) => write!(f, "ai:{}", difficulty),
+            Controller::External => f.write_str("external"),
+        }
+    }
+}
+
+impl FromStr for Controller {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Controller::parse_with_level(s, Difficulty::default())
+    }
+}
+
+/// The two seats of a game. Black always moves first.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Seats {
+    black: Controller,
+    white: Controller,
+}
+
+impl Default for Seats {
+    fn default() -> Self {
+        Seats::new(Controller::Human, Controller::Ai(Difficulty::default()))
+    }
+}
+
+impl Seats {
+    pub fn new(black: Controller, white: Controller) -> Self {
+        Self { black, white }
+    }
+
+    pub fn controller(&self, color: ColorPiece) -> Controller {
+        match color {
+            Black => self.black,
+            White => self.white,
+        }
+    }
+
+    pub fn set_controller(&mut self, color: ColorPiece, controller: Controller) {
+        match color {
+            Black => self.black = controller,
+            White => self.white = controller,
+        }
+    }
+
+    /// Controller of the player who has to move on this board.
+    pub fn to_move(&self, board: &Board) -> Controller {
+        self.controller(board.current_player().color())
+    }
+
+    pub fn has_human(&self) -> bool {
+        self.black == Controller::Human || self.white == Controller::Human
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::moves::Move;
+
+    #[test]
+    fn should_seat_human_as_black_against_ai_by_default() {
+        // Given
+        let seats = Seats::default();
+
+        // When / Then
+        assert_eq!(seats.controller(Black), Controller::Human);
+        assert_eq!(
+            seats.controller(White),
+            Controller::Ai(Difficulty::default())
+        );
+    }
+
+    #[test]
+    fn should_give_controller_of_the_color_to_move() {
+        // Given
+        let seats = Seats::new(Controller::Ai(Difficulty::Expert), Controller::Human);
+        let mut board = Board::default();
+
+        // When / Then
+        assert_eq!(seats.to_move(&board), Controller::Ai(Difficulty::Expert));
+        board.play(Move::Play(5, 4)).expect("Move should be legal");
+        assert_eq!(seats.to_move(&board), Controller::Human);
+    }
+
+    #[test]
+    fn should_parse_controllers() {
+        assert_eq!("Human".parse(), Ok(Controller::Human));
+        assert_eq!("ai".parse(), Ok(Controller::Ai(Difficulty::default())));
+        assert_eq!("AI:expert".parse(), Ok(Controller::Ai(Difficulty::Expert)));
+        assert_eq!("external".parse(), Ok(Controller::External));
+        assert!("robot".parse::<Controller>().is_err());
+        assert!("ai:master".parse::<Controller>().is_err());
+        assert!("human:expert".parse::<Controller>().is_err());
+    }
+
+    #[test]
+    fn should_give_plain_ai_the_chosen_level() {
+        assert_eq!(
+            Controller::parse_with_level("ai", Difficulty::Beginner),
+            Ok(Controller::Ai(Difficulty::Beginner))
+        );
+        assert_eq!(
+            Controller::parse_with_level("ai:advanced", Difficulty::Beginner),
+            Ok(Controller::Ai(Difficulty::Advanced))
+        );
+        let controller = Controller::Ai(Difficulty::Advanced);
+        assert_eq!(controller.to_string().parse(), Ok(controller));
+    }
+}
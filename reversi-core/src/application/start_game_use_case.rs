@@ -1,4 +1,6 @@
 use crate::domain::board::Board;
+use crate::domain::board::ColorPiece::{Black, White};
+use crate::domain::position::PositionError;
 
 pub struct StartGameUseCase {}
 
@@ -6,4 +8,55 @@ impl StartGameUseCase {
     pub fn execute(&self) -> Board {
         Board::default()
     }
+
+    /// The color to move may be blocked in a pasted position: its opponent moves instead, with
+    /// no pass in the history that could be taken back.
+    pub fn execute_from_position(&self, position: &str) -> Result<Board, PositionError> {
+        let board: Board = position.parse()?;
+        let player = board.current_player();
+        if board.end_of_game().is_none() && board.available_positions(player).is_empty() {
+            return Ok(Board::with_position(
+                board.pieces(Black),
+                board.pieces(White),
+                player.opponent_color(),
+            ));
+        }
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_give_the_move_to_the_opponent_of_a_blocked_color() {
+        // Given
+        let position = format!("OX{} X", "-".repeat(62));
+
+        // When
+        let result = StartGameUseCase {}.execute_from_position(&position);
+
+        // Then
+        let mut board = result.expect("Position should be valid");
+        assert_eq!(board.current_player().color(), White);
+        assert!(board.history().is_empty());
+        assert!(!board.can_undo());
+        assert_eq!(board.undo(), None);
+    }
+
+    #[test]
+    fn should_keep_the_color_to_move_when_it_can_play() {
+        // Given
+        let position = format!("{0}OX{1}XO{0} O", "-".repeat(27), "-".repeat(6));
+
+        // When
+        let result = StartGameUseCase {}.execute_from_position(&position);
+
+        // Then
+        let board = result.expect("Position should be valid");
+        assert_eq!(board.current_player().color(), White);
+        assert!(board.history().is_empty());
+        assert_eq!(board.available_positions(board.current_player()).len(), 4);
+    }
 }
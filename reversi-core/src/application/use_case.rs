@@ -1,9 +1,12 @@
 use crate::application::ai_move_use_case::AIMoveUseCase;
 use crate::application::compute_available_moves_use_case::ComputeAvailableMovesUseCase;
+use crate::application::difficulty::Difficulty;
 use crate::application::evaluate_game_end_use_case::EvaluateGameEndUseCase;
 use crate::application::move_use_case::MoveUseCaseImpl;
 use crate::application::player_move_use_case::PlayerMoveUseCase;
+use crate::application::seats::{Controller, Seats};
 use crate::application::start_game_use_case::StartGameUseCase;
+use crate::domain::board::ColorPiece::{Black, White};
 
 pub struct UseCase {
     pub initialize_game_use_case: StartGameUseCase,
@@ -15,12 +18,35 @@ pub struct UseCase {
 
 impl Default for UseCase  {
     fn default() -> Self {
+        Self::new(Difficulty::default())
+    }
+}
+
+impl UseCase {
+    pub fn new(difficulty: Difficulty) -> Self {
         Self {
             initialize_game_use_case: StartGameUseCase {},
             compute_available_moves_use_case: ComputeAvailableMovesUseCase {},
             play_move_use_case: PlayerMoveUseCase::new(Box::new(MoveUseCaseImpl {})),
-            play_ai_move_use_case: AIMoveUseCase::new(Box::new(MoveUseCaseImpl {})),
+            play_ai_move_use_case: AIMoveUseCase::new(
+                Box::new(MoveUseCaseImpl {}),
+                difficulty.strategy(),
+            ),
             evaluate_game_end_use_case: EvaluateGameEndUseCase {},
         }
     }
+
+    pub fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.play_ai_move_use_case.set_strategy(difficulty.strategy());
+    }
+
+    /// Gives each AI seat the strategy of its level.
+    pub fn set_seats(&mut self, seats: &Seats) {
+        for color in [Black, White] {
+            if let Controller::Ai(difficulty) = seats.controller(color) {
+                self.play_ai_move_use_case
+                    .set_color_strategy(color, difficulty.strategy());
+            }
+        }
+    }
 }
@@ -0,0 +1,289 @@
+//! Engine speaking the NBoard protocol on the standard input and output, to play in
+//! NBoard-compatible GUIs or against other programs.
+//!
+//! Supported commands: `nboard`, `set depth`, `set game` (GGF), `move`, `hint`, `go`, `ping`
+//! and `learn`. The others are ignored.
+
+use reversi_core::domain::board::Board;
+use reversi_core::domain::ggf::GgfGame;
+use reversi_core::domain::moves::Move;
+use reversi_core::domain::search::{Budget, EndgameSolver, NegamaxStrategy, SearchControl};
+use std::io::{self, BufRead, Write};
+use std::time::{Duration, Instant};
+
+const ENGINE_NAME: &str = "reversi-core";
+const DEFAULT_DEPTH: u8 = 8;
+/// Deepest search the engine accepts, the one of the Expert level.
+const MAX_DEPTH: u8 = 20;
+/// Most empty cells the engine solves exactly, whatever the depth.
+const MAX_SOLVED_EMPTIES: u32 = 16;
+/// Longest the engine thinks before answering, so that a deep search never hangs the GUI.
+const MOVE_TIME: Duration = Duration::from_secs(10);
+
+struct Engine {
+    board: Board,
+    strategy: NegamaxStrategy,
+    solver: EndgameSolver,
+}
+
+impl Engine {
+    fn new(depth: u8) -> Self {
+        let mut engine = Self {
+            board: Board::default(),
+            strategy: NegamaxStrategy::new(depth).with_budget(Budget::Time(MOVE_TIME)),
+            solver: EndgameSolver::new(0),
+        };
+        engine.set_depth(depth);
+        engine
+    }
+
+    /// Only the depths change, the tables of the previous searches are kept.
+    fn set_depth(&mut self, depth: u8) {
+        let depth = depth.clamp(1, MAX_DEPTH);
+        self.strategy.set_depth(depth);
+        self.solver
+            .set_max_empties(u32::from(depth).min(MAX_SOLVED_EMPTIES));
+    }
+
+    /// Replies to one line of the GUI.
+    fn handle(&mut self, line: &str) -> Vec<String> {
+        let (command, arguments) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+        match command {
+            "nboard" => vec![format!("set myname {}", ENGINE_NAME)],
+            "ping" => vec![format!("pong {}", arguments.trim())],
+            "learn" => vec!["learned".to_string()],
+            "set" => self.set(arguments.trim()),
+            "move" => self.play(arguments.trim()),
+            "go" => match self.search() {
+                Some((position, _)) => vec![format!("=== {}", nboard_move(position))],
+                None => vec![format!("=== {}", nboard_move(Move::Pass))],
+            },
+            "hint" => self.hint(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set(&mut self, arguments: &str) -> Vec<String> {
+        let (name, value) = arguments.split_once(' ').unwrap_or((arguments, ""));
+        match name {
+            "depth" => match value.trim().parse() {
+                Ok(depth) => self.set_depth(depth),
+                Err(_) => eprintln!("invalid depth \"{}\"", value),
+            },
+            "game" => match value.parse::<GgfGame>().and_then(|game| game.replay()) {
+                Ok(board) => self.board = board,
+                Err(error) => eprintln!("invalid game: {}", error),
+            },
+            _ => {}
+        }
+        Vec::new()
+    }
+
+    /// Plays a move given as `F5`, possibly followed by `/eval/time`.
+    fn play(&mut self, text: &str) -> Vec<String> {
+        let text = text.split('/').next().unwrap_or_default();
+        match text.to_ascii_lowercase().parse::<Move>() {
+            // The board plays the passes on its own
+            Ok(Move::Pass) => {}
+            Ok(mv) => {
+                if let Err(error) = self.board.play(mv) {
+                    eprintln!("illegal move {}: {}", text, error);
+                }
+            }
+            Err(error) => eprintln!("{}", error),
+        }
+        Vec::new()
+    }
+
+    fn hint(&self) -> Vec<String> {
+        match self.search() {
+            Some((_, line)) => vec![line, "status".to_string()],
+            None => vec!["status".to_string()],
+        }
+    }
+
+    /// Best move with its `search` line: the disc difference once the position is solved, the
+    /// score of the search otherwise. The solver gets half of the time, the search what it
+    /// leaves.
+    fn search(&self) -> Option<(Move, String)> {
+        let control = SearchControl::default();
+        let start = Instant::now();
+        if let Some(solution) =
+            self.solver
+                .solve_controlled(&self.board, &control, Some(Budget::Time(MOVE_TIME / 2)))
+        {
+            let (x, y) = solution.position();
+            let mv = Move::Play(x, y);
+            let line = format!(
+                "search {} {} 0 100%",
+                nboard_move(mv),
+                solution.differential()
+            );
+            return Some((mv, line));
+        }
+        let budget = Budget::Time(MOVE_TIME.saturating_sub(start.elapsed()));
+        let analysis = self
+            .strategy
+            .analyze_within(&self.board, &control, Some(budget))?;
+        let (x, y) = analysis.position();
+        let mv = Move::Play(x, y);
+        let line = format!(
+            "search {} {} 0 {}",
+            nboard_move(mv),
+            analysis.score(),
+            analysis.depth()
+        );
+        Some((mv, line))
+    }
+}
+
+fn nboard_move(mv: Move) -> String {
+    mv.to_string().to_ascii_uppercase()
+}
+
+fn main() {
+    let mut engine = Engine::new(DEFAULT_DEPTH);
+    let mut stdout = io::stdout().lock();
+    for line in io::stdin().lock().lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        for reply in engine.handle(&line) {
+            if writeln!(stdout, "{}", reply)
+                .and_then(|_| stdout.flush())
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAME: &str = "(;GM[Othello]PC[NBoard]PB[Black]PW[White]RE[?]TI[5:00]TY[8]\
+        BO[8 ---------------------------O*------*O--------------------------- *]\
+        B[F5]W[D6//1.2]B[C3];)";
+
+    #[test]
+    fn should_answer_greeting_and_ping() {
+        let mut engine = Engine::new(1);
+        assert_eq!(engine.handle("nboard 2"), vec!["set myname reversi-core"]);
+        assert_eq!(engine.handle("ping 3"), vec!["pong 3"]);
+    }
+
+    #[test]
+    fn should_set_game_and_play_moves() {
+        // Given
+        let mut engine = Engine::new(2);
+
+        // When
+        engine.handle(&format!("set game {}", GAME));
+        engine.handle("move D3/0.50/1.2");
+
+        // Then
+        let expected = Board::from_transcript("f5d6c3d3").expect("Transcript should be legal");
+        assert_eq!(engine.board.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn should_play_legal_move_on_go() {
+        // Given
+        let mut engine = Engine::new(2);
+        engine.handle(&format!("set game {}", GAME));
+
+        // When
+        let replies = engine.handle("go");
+
+        // Then
+        assert_eq!(replies.len(), 1);
+        let text = replies[0]
+            .strip_prefix("=== ")
+            .expect("Move should be sent");
+        let Ok(Move::Play(x, y)) = text.to_ascii_lowercase().parse() else {
+            panic!("Move should be valid: {}", text);
+        };
+        assert!(
+            engine
+                .board
+                .available_positions(engine.board.current_player())
+                .contains(&(x, y))
+        );
+    }
+
+    #[test]
+    fn should_play_perfect_move_on_go_near_the_end() {
+        for seed in 0..5 {
+            // Given
+            let mut rng = fastrand::Rng::with_seed(seed);
+            let mut board = Board::default();
+            while board.empties() > 6 && board.end_of_game().is_none() {
+                let moves = board.available_positions(board.current_player());
+                let (x, y) = moves[rng.usize(0..moves.len())];
+                board.place(x, y).expect("Move should be legal");
+            }
+            let Some(solution) = EndgameSolver::new(6).solve(&board) else {
+                continue;
+            };
+            let mut engine = Engine::new(6);
+            engine.board = board;
+
+            // When
+            let replies = engine.handle("go");
+
+            // Then
+            let (x, y) = solution.position();
+            assert_eq!(
+                replies,
+                vec![format!("=== {}", nboard_move(Move::Play(x, y)))]
+            );
+        }
+    }
+
+    #[test]
+    fn should_give_exact_hint_near_the_end() {
+        // Given: black takes a1 and wins every disc
+        let mut engine = Engine::new(4);
+        let position = format!("-O{} *", "*".repeat(62));
+        engine.handle(&format!("set game (;GM[Othello]BO[8 {}];)", position));
+
+        // When
+        let replies = engine.handle("hint 1");
+
+        // Then
+        assert_eq!(replies, vec!["search A1 64 0 100%", "status"]);
+    }
+
+    #[test]
+    fn should_keep_board_when_depth_changes() {
+        // Given
+        let mut engine = Engine::new(2);
+        engine.handle(&format!("set game {}", GAME));
+
+        // When
+        engine.handle("set depth 5");
+
+        // Then
+        let expected = Board::from_transcript("f5d6c3").expect("Transcript should be legal");
+        assert_eq!(engine.board.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn should_search_at_new_depth_after_it_changes() {
+        // Given
+        let mut engine = Engine::new(1);
+        engine.handle(&format!("set game {}", GAME));
+
+        // When
+        engine.handle("set depth 3");
+        let replies = engine.handle("hint 1");
+
+        // Then
+        let search = replies[0]
+            .strip_prefix("search ")
+            .expect("Search should be sent");
+        assert!(search.ends_with(" 0 3"), "{}", search);
+    }
+}
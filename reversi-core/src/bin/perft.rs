@@ -0,0 +1,59 @@
+//! Prints the perft counts of a position, one line per depth.
+//!
+//! `perft [--divide] [depth] [position]`, from the initial position to depth 9 by default. With
+//! `--divide`, the count of the last depth is also given for each move.
+
+use reversi_core::domain::board::Board;
+use reversi_core::domain::moves::Move;
+use reversi_core::domain::perft::{divide, perft};
+use std::process::ExitCode;
+use std::time::Instant;
+
+const DEFAULT_DEPTH: u32 = 9;
+
+fn main() -> ExitCode {
+    let mut divide_moves = false;
+    let mut depth = DEFAULT_DEPTH;
+    let mut position = Vec::new();
+    for arg in std::env::args().skip(1) {
+        if arg == "--divide" {
+            divide_moves = true;
+        } else if let Ok(value) = arg.parse() {
+            depth = value;
+        } else {
+            position.push(arg);
+        }
+    }
+
+    let board = if position.is_empty() {
+        Board::default()
+    } else {
+        match position.join(" ").parse::<Board>() {
+            Ok(board) => board,
+            Err(error) => {
+                eprintln!("Invalid position: {}", error);
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+
+    println!("{}", board);
+    for depth in 1..=depth {
+        let start = Instant::now();
+        let nodes = perft(&board, depth);
+        let elapsed = start.elapsed().as_secs_f64();
+        println!(
+            "perft({:>2}) = {:>14}  {:>8.3}s  {:>12.0} nodes/s",
+            depth,
+            nodes,
+            elapsed,
+            nodes as f64 / elapsed.max(f64::EPSILON)
+        );
+    }
+    if divide_moves {
+        for ((x, y), nodes) in divide(&board, depth) {
+            println!("{}: {}", Move::Play(x, y), nodes);
+        }
+    }
+    ExitCode::SUCCESS
+}
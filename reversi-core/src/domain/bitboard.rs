@@ -0,0 +1,211 @@
+// One bit per cell: the cell (x, y) is stored at bit `y * 8 + x`, so iterating bits from the
+// lowest to the highest visits the cells in the same order as `BoardIter`.
+
+const NOT_FIRST_COLUMN: u64 = 0xfefe_fefe_fefe_fefe;
+const NOT_LAST_COLUMN: u64 = 0x7f7f_7f7f_7f7f_7f7f;
+const ALL: u64 = u64::MAX;
+
+// (shift, mask) for each direction: a positive shift moves towards higher bits. The mask drops
+// the pieces which wrapped around from one side of the board to the other.
+const SHIFTS: [(i32, u64); 8] = [
+    (-9, NOT_LAST_COLUMN),
+    (-8, ALL),
+    (-7, NOT_FIRST_COLUMN),
+    (-1, NOT_LAST_COLUMN),
+    (1, NOT_FIRST_COLUMN),
+    (7, NOT_LAST_COLUMN),
+    (8, ALL),
+    (9, NOT_FIRST_COLUMN),
+];
+
+#[inline]
+fn shift(bitboard: u64, (shift, mask): (i32, u64)) -> u64 {
+    if shift > 0 {
+        (bitboard << shift) & mask
+    } else {
+        (bitboard >> -shift) & mask
+    }
+}
+
+pub(crate) fn square(x: usize, y: usize) -> u64 {
+    1 << (y * 8 + x)
+}
+
+pub(crate) fn moves(player: u64, opponent: u64) -> u64 {
+    let empty = !(player | opponent);
+    let mut moves = 0;
+    for direction in SHIFTS {
+        let mut candidates = shift(player, direction) & opponent;
+        for _ in 0..5 {
+            candidates |= shift(candidates, direction) & opponent;
+        }
+        moves |= shift(candidates, direction) & empty;
+    }
+    moves
+}
+
+pub(crate) fn flips(player: u64, opponent: u64, square: u64) -> u64 {
+    let mut flips = 0;
+    for direction in SHIFTS {
+        let mut line = 0;
+        let mut cursor = shift(square, direction);
+        while cursor & opponent != 0 {
+            line |= cursor;
+            cursor = shift(cursor, direction);
+        }
+        if cursor & player != 0 {
+            flips |= line;
+        }
+    }
+    flips
+}
+
+/// Cells next to at least one of the given cells.
+pub(crate) fn neighbours(bitboard: u64) -> u64 {
+    SHIFTS.iter().fold(0, |neighbours, direction| {
+        neighbours | shift(bitboard, *direction)
+    })
+}
+
+/// Pieces of the player which can never be flipped again: along each of the four lines through
+/// them, the line is full or they touch the edge or a stable piece on one side.
+pub(crate) fn stable(player: u64, opponent: u64) -> u64 {
+    let occupied = player | opponent;
+    // SHIFTS[k] and SHIFTS[7 - k] are opposite directions
+    let lines = [0, 1, 2, 3].map(|k| (SHIFTS[k], SHIFTS[7 - k]));
+    let full = lines.map(|(forward, backward)| full_line(occupied, forward, backward));
+
+    let mut stable = 0;
+    loop {
+        let mut candidates = player;
+        for ((forward, backward), full) in lines.iter().zip(full) {
+            let protected_forward = !shift(ALL, *backward) | shift(stable, *backward);
+            let protected_backward = !shift(ALL, *forward) | shift(stable, *forward);
+            candidates &= full | protected_forward | protected_backward;
+        }
+        if candidates == stable {
+            return stable;
+        }
+        stable = candidates;
+    }
+}
+
+/// Occupied cells whose whole line between these two opposite directions is occupied.
+fn full_line(occupied: u64, forward: (i32, u64), backward: (i32, u64)) -> u64 {
+    // `!shift(ALL, backward)` holds the cells without neighbour in the forward direction
+    let mut full_forward = 0;
+    let mut full_backward = 0;
+    for _ in 0..8 {
+        full_forward = occupied & (!shift(ALL, backward) | shift(full_forward, backward));
+        full_backward = occupied & (!shift(ALL, forward) | shift(full_backward, forward));
+    }
+    full_forward & full_backward
+}
+
+pub(crate) struct Squares(pub(crate) u64);
+
+impl Iterator for Squares {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Some((index % 8, index / 8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_not_wrap_around_columns_when_shifting() {
+        // Given
+        let last_column = square(7, 3);
+        let first_column = square(0, 3);
+
+        // When / Then
+        assert_eq!(shift(last_column, (1, NOT_FIRST_COLUMN)), 0);
+        assert_eq!(shift(last_column, (9, NOT_FIRST_COLUMN)), 0);
+        assert_eq!(shift(first_column, (-1, NOT_LAST_COLUMN)), 0);
+        assert_eq!(shift(first_column, (7, NOT_LAST_COLUMN)), 0);
+    }
+
+    #[test]
+    fn should_iterate_squares_in_board_order() {
+        // Given
+        let bitboard = square(5, 4) | square(3, 2) | square(4, 5) | square(2, 3);
+
+        // When
+        let squares: Vec<(usize, usize)> = Squares(bitboard).collect();
+
+        // Then
+        assert_eq!(squares, vec![(3, 2), (2, 3), (5, 4), (4, 5)]);
+    }
+
+    #[test]
+    fn should_find_stable_pieces_from_corner_along_edges() {
+        // Given
+        let player = square(0, 0) | square(1, 0) | square(2, 0) | square(0, 1) | square(1, 1);
+        let opponent = square(3, 3);
+
+        // When
+        let stable = stable(player, opponent);
+
+        // Then
+        assert_eq!(
+            stable,
+            square(0, 0) | square(1, 0) | square(2, 0) | square(0, 1) | square(1, 1)
+        );
+    }
+
+    #[test]
+    fn should_not_find_stable_pieces_away_from_edges() {
+        // Given
+        let player = square(0, 0) | square(2, 0) | square(3, 3);
+        let opponent = square(4, 4);
+
+        // When
+        let stable = stable(player, opponent);
+
+        // Then
+        assert_eq!(
+            stable,
+            square(0, 0),
+            "b1 is empty so c1 may still be flipped"
+        );
+    }
+
+    #[test]
+    fn should_find_pieces_stable_on_full_lines() {
+        // Given: the whole board is full
+        let player = 0x0000_0000_ff00_0000;
+        let opponent = !player;
+
+        // When
+        let stable = stable(player, opponent);
+
+        // Then
+        assert_eq!(stable, player);
+    }
+
+    #[test]
+    fn should_flip_in_several_directions() {
+        // Given
+        let player = square(0, 0) | square(4, 0) | square(2, 4);
+        let opponent = square(1, 0) | square(3, 0) | square(2, 1) | square(2, 3);
+
+        // When
+        let flips = flips(player, opponent, square(2, 0));
+
+        // Then
+        assert_eq!(
+            flips,
+            square(1, 0) | square(3, 0),
+            "the column is not flipped because it is interrupted by an empty cell"
+        );
+    }
+}
@@ -1,7 +1,11 @@
+use crate::domain::bitboard;
+use crate::domain::bitboard::Squares;
 use crate::domain::board::Case::{Empty, Piece};
 use crate::domain::board::ColorPiece::{Black, White};
-use crate::domain::directions::Directions;
+use crate::domain::moves::{Move, MoveError, MoveOutcome};
 use crate::domain::player::Player;
+use crate::domain::zobrist;
+use std::cmp::Ordering;
 
 #[derive(Copy, Clone, PartialEq)]
 #[cfg_attr(test, derive(Debug))]
@@ -11,7 +15,7 @@ pub enum Case {
 }
 
 impl Case {
-    fn flip(&mut self) {
+    pub fn flip(&mut self) {
         *self = match self {
             Empty => Empty,
             Piece(White) => Piece(Black),
@@ -27,21 +31,36 @@ pub enum ColorPiece {
     Black,
 }
 
-#[derive(PartialEq)]
+#[derive(Copy, Clone, PartialEq)]
 #[cfg_attr(test, derive(Debug))]
 pub enum PlayerId {
     Player1,
     Player2,
 }
 
+#[derive(Copy, Clone)]
+struct Turn {
+    mv: Move,
+    flipped: u64,
+    player: PlayerId,
+    passed: bool,
+}
+
+#[derive(Clone)]
 pub struct Board {
-    array: [Case; 64],
+    black: u64,
+    white: u64,
     current_player: PlayerId,
     player1: Player,
     player2: Player,
+    history: Vec<Turn>,
+    undone: Vec<Turn>,
+    hash: u64,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Score {
     player1: usize,
     player2: usize,
@@ -54,40 +73,70 @@ impl Score {
     pub fn player2(&self) -> usize {
         self.player2
     }
+
+    /// Color with the most pieces, `None` for a draw. Player 1 always plays Black.
+    pub fn winner(&self) -> Option<ColorPiece> {
+        match self.player1.cmp(&self.player2) {
+            Ordering::Greater => Some(Black),
+            Ordering::Less => Some(White),
+            Ordering::Equal => None,
+        }
+    }
 }
 
 impl Default for Board {
     fn default() -> Self {
-        let mut array = [Empty; 64];
-        array[27] = Piece(White);
-        array[28] = Piece(Black);
-        array[35] = Piece(Black);
-        array[36] = Piece(White);
+        Board::with_pieces(
+            bitboard::square(4, 3) | bitboard::square(3, 4),
+            bitboard::square(3, 3) | bitboard::square(4, 4),
+        )
+    }
+}
+
+impl Board {
+    fn with_pieces(black: u64, white: u64) -> Board {
         Board {
-            array,
+            black,
+            white,
             current_player: PlayerId::Player1,
             player1: Player::new(Black),
             player2: Player::new(White),
+            history: Vec::new(),
+            undone: Vec::new(),
+            hash: zobrist::hash(black, white, Black),
         }
     }
-}
 
-impl Board {
+    pub(crate) fn with_position(black: u64, white: u64, color_to_move: ColorPiece) -> Board {
+        let mut board = Board::with_pieces(black, white);
+        if color_to_move == White {
+            board.switch_player();
+        }
+        board
+    }
+
     #[cfg(test)]
     pub fn create_board_for_test(array: [Case; 64]) -> Board {
-        Board {
-            array,
-            current_player: PlayerId::Player1,
-            player1: Player::new(Black),
-            player2: Player::new(White),
+        let mut board = Board::with_pieces(0, 0);
+        for (index, case) in array.iter().enumerate() {
+            let square = bitboard::square(index / 8, index % 8);
+            match case {
+                Empty => {}
+                Piece(Black) => board.black |= square,
+                Piece(White) => board.white |= square,
+            }
         }
+        board.hash = zobrist::hash(board.black, board.white, Black);
+        board
+    }
+
+    /// Zobrist hash of the position: pieces and color to move.
+    pub fn hash(&self) -> u64 {
+        self.hash
     }
 
     pub fn current_player(&self) -> &Player {
-        match self.current_player {
-            PlayerId::Player1 => &self.player1,
-            PlayerId::Player2 => &self.player2,
-        }
+        self.player(self.current_player)
     }
 
     pub fn player1(&self) -> bool {
@@ -99,142 +148,222 @@ impl Board {
     }
 
     pub fn available_positions(&self, player: &Player) -> Vec<(usize, usize)> {
-        let mut available_positions = Vec::new();
+        Squares(self.legal_moves(player.color())).collect()
+    }
 
-        for (x, y) in BoardIter::default() {
-            if self.cell(x, y) != Some(&Empty) {
-                continue;
+    pub fn place(&mut self, x: usize, y: usize) -> Result<Vec<(usize, usize)>, MoveError> {
+        self.play(Move::Play(x, y)).map(MoveOutcome::into_flipped)
+    }
+
+    /// Plays a move for the current player and reports whether the opponent had to pass right
+    /// after it. `Move::Pass` is only accepted when the current player has no move left while
+    /// the game is not over.
+    pub fn play(&mut self, mv: Move) -> Result<MoveOutcome, MoveError> {
+        let player = self.current_player;
+        let flipped = match mv {
+            Move::Play(x, y) => {
+                if !(0..8).contains(&x) || !(0..8).contains(&y) {
+                    return Err(MoveError::OutOfBounds);
+                }
+                match self.flip(x, y) {
+                    Some(flipped) => flipped,
+                    None if self.end_of_game().is_some() => return Err(MoveError::GameOver),
+                    None if self.cell(x, y) != Some(&Empty) => return Err(MoveError::Occupied),
+                    None => return Err(MoveError::NoFlip),
+                }
             }
-            let directions = Directions::default();
-            for (dx, dy) in directions {
-                let pieces = self.scan_flips_in_direction(
-                    x,
-                    y,
-                    dx,
-                    dy,
-                    player.opponent_color(),
-                    player.color(),
-                );
-                if pieces.is_some() {
-                    available_positions.push((x, y));
-                    break;
+            Move::Pass => {
+                if self.end_of_game().is_some() {
+                    return Err(MoveError::GameOver);
                 }
+                if self.legal_moves(self.current_player().color()) != 0 {
+                    return Err(MoveError::MustPlay);
+                }
+                0
             }
+        };
+        let passed = self.apply(mv);
+        self.history.push(Turn {
+            mv,
+            flipped,
+            player,
+            passed,
+        });
+        self.undone.clear();
+        Ok(MoveOutcome::new(
+            Squares(flipped).collect(),
+            passed.then(|| self.current_player().opponent_color()),
+        ))
+    }
+
+    /// Puts the piece of the current player, switches to the opponent and switches back if the
+    /// opponent cannot move. Returns whether the opponent had to pass while the game goes on.
+    fn apply(&mut self, mv: Move) -> bool {
+        if let Move::Play(x, y) = mv {
+            self.toggle_piece(self.current_player().color(), bitboard::square(x, y));
+        }
+        self.switch_player();
+
+        if self.legal_moves(self.current_player().color()) == 0 {
+            self.switch_player();
+            return self.legal_moves(self.current_player().color()) != 0;
         }
-        available_positions
+        false
     }
 
-    pub fn place(&mut self, x: usize, y: usize) -> Option<Vec<(usize, usize)>> {
-        if !(0..8).contains(&x) || !(0..8).contains(&y) {
-            return None;
+    /// Takes back the last move. The player who made it becomes the current player again, even
+    /// if the opponent had to pass after it.
+    pub fn undo(&mut self) -> Option<Move> {
+        let turn = self.history.pop()?;
+        if let Move::Play(x, y) = turn.mv {
+            self.toggle_piece(self.player(turn.player).color(), bitboard::square(x, y));
         }
-        let flip_pieces = self.flip(x, y)?;
-        self.array[x * 8 + y] = Piece(self.current_player().color());
-        self.switch_player();
+        self.toggle_flips(turn.flipped);
+        self.set_current_player(turn.player);
+        self.undone.push(turn);
+        Some(turn.mv)
+    }
 
-        if self.available_positions(self.current_player()).is_empty() {
-            self.switch_player();
+    /// Plays again the last move taken back by `undo`.
+    pub fn redo(&mut self) -> Option<Move> {
+        let turn = self.undone.pop()?;
+        self.toggle_flips(turn.flipped);
+        self.set_current_player(turn.player);
+        self.apply(turn.mv);
+        self.history.push(turn);
+        Some(turn.mv)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.history.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    /// Moves played since the start of the game, oldest first, including the passes.
+    pub fn history(&self) -> Vec<Move> {
+        let mut moves = Vec::with_capacity(self.history.len());
+        for turn in &self.history {
+            moves.push(turn.mv);
+            if turn.passed {
+                moves.push(Move::Pass);
+            }
+        }
+        moves
+    }
+
+    fn player(&self, id: PlayerId) -> &Player {
+        match id {
+            PlayerId::Player1 => &self.player1,
+            PlayerId::Player2 => &self.player2,
         }
-        Some(flip_pieces)
     }
 
-    fn flip(&mut self, x: usize, y: usize) -> Option<Vec<(usize, usize)>> {
-        if matches!(self.cell(x, y), Some(Piece(_))) {
+    fn flip(&mut self, x: usize, y: usize) -> Option<u64> {
+        let square = bitboard::square(x, y);
+        if (self.black | self.white) & square != 0 {
             return None;
         }
 
         let player = self.current_player().color();
         let opponent = self.current_player().opponent_color();
-        let mut flipped_pieces = Vec::new();
-
-        let directions = Directions::default();
-        for (dx, dy) in directions {
-            let pieces = self.scan_flips_in_direction(x, y, dx, dy, opponent, player);
-            if let Some(mut pieces) = pieces {
-                for piece in &pieces {
-                    self.array[piece.0 * 8 + piece.1].flip();
-                }
-                flipped_pieces.append(&mut pieces);
-            };
-        }
-        if flipped_pieces.is_empty() {
-            None
-        } else {
-            Some(flipped_pieces)
+        let flipped = bitboard::flips(self.pieces(player), self.pieces(opponent), square);
+        if flipped == 0 {
+            return None;
         }
+        self.toggle_flips(flipped);
+        Some(flipped)
     }
 
-    fn scan_flips_in_direction(
-        &self,
-        x: usize,
-        y: usize,
-        dx: isize,
-        dy: isize,
-        opponent: ColorPiece,
-        player: ColorPiece,
-    ) -> Option<Vec<(usize, usize)>> {
-        if dx == 0 && dy == 0 {
-            return None;
+    pub(crate) fn pieces(&self, color: ColorPiece) -> u64 {
+        match color {
+            Black => self.black,
+            White => self.white,
         }
+    }
 
-        let mut flips = Vec::new();
+    fn pieces_mut(&mut self, color: ColorPiece) -> &mut u64 {
+        match color {
+            Black => &mut self.black,
+            White => &mut self.white,
+        }
+    }
 
-        let mut nx = x.checked_add_signed(dx)?;
-        let mut ny = y.checked_add_signed(dy)?;
+    /// Puts or removes a piece, keeping the hash up to date.
+    fn toggle_piece(&mut self, color: ColorPiece, square: u64) {
+        *self.pieces_mut(color) ^= square;
+        self.hash ^= zobrist::pieces(color, square);
+    }
 
-        match self.cell(nx, ny)? {
-            Piece(color) if color == &opponent => flips.push((nx, ny)),
-            _ => return None,
-        }
+    /// Changes the color of the pieces, keeping the hash up to date.
+    fn toggle_flips(&mut self, flipped: u64) {
+        self.black ^= flipped;
+        self.white ^= flipped;
+        self.hash ^= zobrist::flips(flipped);
+    }
 
-        loop {
-            nx = nx.checked_add_signed(dx)?;
-            ny = ny.checked_add_signed(dy)?;
+    pub(crate) fn legal_moves(&self, color: ColorPiece) -> u64 {
+        let opponent = match color {
+            Black => White,
+            White => Black,
+        };
+        bitboard::moves(self.pieces(color), self.pieces(opponent))
+    }
 
-            match self.cell(nx, ny)? {
-                Empty => return None,
-                Piece(color) if color == &player => return Some(flips),
-                Piece(_color) => flips.push((nx, ny)),
-            }
-        }
+    /// Number of cells without any piece.
+    pub fn empties(&self) -> u32 {
+        (self.black | self.white).count_zeros()
     }
 
     pub fn cell(&self, i: usize, j: usize) -> Option<&Case> {
         if i > 7 || j > 7 {
             return None;
         }
-        self.array.get(i * 8 + j)
+        let square = bitboard::square(i, j);
+        if self.black & square != 0 {
+            Some(&Piece(Black))
+        } else if self.white & square != 0 {
+            Some(&Piece(White))
+        } else {
+            Some(&Empty)
+        }
     }
 
     fn switch_player(&mut self) {
         self.current_player = match self.current_player {
             PlayerId::Player1 => PlayerId::Player2,
             PlayerId::Player2 => PlayerId::Player1,
+        };
+        self.hash ^= zobrist::WHITE_TO_MOVE;
+    }
+
+    fn set_current_player(&mut self, player: PlayerId) {
+        if self.current_player != player {
+            self.switch_player();
         }
     }
 
     pub fn end_of_game(&self) -> Option<Score> {
-        let board_has_cell_empty = self.array.contains(&Empty);
+        let board_has_cell_empty = (self.black | self.white) != u64::MAX;
         if !board_has_cell_empty
-            || (self.available_positions(&self.player1).is_empty()
-                && self.available_positions(&self.player2).is_empty())
+            || (self.legal_moves(self.player1.color()) == 0
+                && self.legal_moves(self.player2.color()) == 0)
         {
-            Some(Score {
-                player1: self
-                    .array
-                    .iter()
-                    .filter(|&&c| c == Piece(self.player1.color()))
-                    .count(),
-                player2: self
-                    .array
-                    .iter()
-                    .filter(|&&c| c == Piece(self.player2.color()))
-                    .count(),
-            })
+            Some(self.score())
         } else {
             None
         }
     }
+
+    /// Pieces of each player so far, the final score once the game is over.
+    pub fn score(&self) -> Score {
+        Score {
+            player1: self.pieces(self.player1.color()).count_ones() as usize,
+            player2: self.pieces(self.player2.color()).count_ones() as usize,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -308,6 +437,7 @@ mod tests {
         let score = result.expect("Score must be Some");
         assert_eq!(score.player1(), 0);
         assert_eq!(score.player2(), 64);
+        assert_eq!(score.winner(), Some(White));
     }
 
     #[test]
@@ -323,6 +453,7 @@ mod tests {
         let score = result.expect("Score must be Some");
         assert_eq!(score.player1(), 64);
         assert_eq!(score.player2(), 0);
+        assert_eq!(score.winner(), Some(Black));
     }
 
     #[test]
@@ -355,6 +486,21 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn should_count_pieces_of_each_player_during_the_game() {
+        // Given
+        let mut board = Board::default();
+        board.place(5, 4).expect("Move should be legal");
+
+        // When
+        let result = board.score();
+
+        // Then
+        assert_eq!(result.player1(), 4);
+        assert_eq!(result.player2(), 1);
+        assert!(board.end_of_game().is_none());
+    }
+
     #[test]
     fn should_flip_white_piece_to_black() {
         // Given
@@ -444,7 +590,7 @@ mod tests {
         // Then
         assert!(result.is_some());
         let flipped_pieces = result.expect("Should have flipped pieces");
-        assert_eq!(flipped_pieces, vec![(3, 3)]);
+        assert_eq!(Squares(flipped_pieces).collect::<Vec<_>>(), vec![(3, 3)]);
         assert_eq!(board.cell(3, 3), Some(&Piece(Black)));
     }
 
@@ -459,7 +605,7 @@ mod tests {
         let result = board.place(x, y);
 
         // Then
-        assert!(result.is_none());
+        assert_eq!(result, Err(MoveError::Occupied));
     }
 
     #[test]
@@ -473,7 +619,7 @@ mod tests {
         let result = board.place(x, y);
 
         // Then
-        assert!(result.is_none());
+        assert_eq!(result, Err(MoveError::NoFlip));
     }
 
     #[test]
@@ -487,7 +633,7 @@ mod tests {
         let result = board.place(x, y);
 
         // Then
-        assert!(result.is_some());
+        assert!(result.is_ok());
         let flipped_pieces = result.expect("Should have flipped pieces");
         assert_eq!(flipped_pieces, vec![(3, 3)]);
         assert_eq!(board.cell(3, 3), Some(&Piece(Black)));
@@ -496,7 +642,7 @@ mod tests {
     }
 
     #[test]
-    fn should_return_none_when_place_is_out_of_bounds() {
+    fn should_return_error_when_place_is_out_of_bounds() {
         // Given
         let mut board = Board::default();
         let x = 8;
@@ -505,7 +651,319 @@ mod tests {
         // When
         let result = board.place(x, y);
 
+        // Then
+        assert_eq!(result, Err(MoveError::OutOfBounds));
+    }
+
+    #[test]
+    fn should_not_place_when_game_is_over() {
+        // Given
+        let mut array = [Empty; 64];
+        array[3 * 8 + 3] = Piece(Black);
+        array[3 * 8 + 4] = Piece(Black);
+        let mut board = Board::create_board_for_test(array);
+
+        // When
+        let result = board.place(3, 5);
+
+        // Then
+        assert_eq!(result, Err(MoveError::GameOver));
+    }
+
+    #[test]
+    fn should_not_wrap_around_board_edges() {
+        // Given
+        let mut array = [Empty; 64];
+        array[7 * 8 + 3] = Piece(White);
+        array[6 * 8 + 3] = Piece(Black);
+        array[4] = Piece(White);
+        let board = Board::create_board_for_test(array);
+
+        // When
+        let result = board.available_positions(board.current_player());
+
+        // Then
+        assert_eq!(result, Vec::<(usize, usize)>::new());
+    }
+
+    fn scan_reference_moves(board: &Board) -> Vec<(usize, usize)> {
+        let player = board.current_player().color();
+        let opponent = board.current_player().opponent_color();
+        BoardIter::default()
+            .filter(|&(x, y)| board.cell(x, y) == Some(&Empty))
+            .filter(|&(x, y)| {
+                crate::domain::directions::Directions::default().any(|(dx, dy)| {
+                    let mut nx = x as isize + dx;
+                    let mut ny = y as isize + dy;
+                    let mut seen_opponent = false;
+                    while let Some(&Piece(color)) = board.cell(nx as usize, ny as usize) {
+                        if color == opponent {
+                            seen_opponent = true;
+                        } else {
+                            return seen_opponent && color == player;
+                        }
+                        nx += dx;
+                        ny += dy;
+                    }
+                    false
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_generate_same_moves_as_cell_scanning_during_random_games() {
+        for seed in 0..20 {
+            // Given
+            let mut rng = fastrand::Rng::with_seed(seed);
+            let mut board = Board::default();
+
+            while board.end_of_game().is_none() {
+                // When
+                let moves = board.available_positions(board.current_player());
+
+                // Then
+                assert_eq!(moves, scan_reference_moves(&board));
+                let (x, y) = moves[rng.usize(0..moves.len())];
+                assert!(board.place(x, y).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn should_do_nothing_when_undoing_without_history() {
+        // Given
+        let mut board = Board::default();
+
+        // When
+        let result = board.undo();
+
         // Then
         assert!(result.is_none());
+        assert!(!board.can_redo());
+    }
+
+    #[test]
+    fn should_restore_board_and_player_when_undoing_a_move() {
+        // Given
+        let mut board = Board::default();
+        board.place(3, 2).expect("Move should be legal");
+
+        // When
+        let result = board.undo();
+
+        // Then
+        assert_eq!(result, Some(Move::Play(3, 2)));
+        assert_eq!(board.cell(3, 2), Some(&Empty));
+        assert_eq!(board.cell(3, 3), Some(&Piece(White)));
+        assert_eq!(board.current_player, PlayerId::Player1);
+        assert!(!board.can_undo());
+        assert!(board.can_redo());
+    }
+
+    #[test]
+    fn should_restore_player_when_undoing_a_move_followed_by_a_pass() {
+        // Given
+        let mut board: Board = format!("OX{} O", "-".repeat(62))
+            .parse()
+            .expect("Position should be valid");
+        board.place(2, 0).expect("Move should be legal");
+        assert_eq!(
+            board.current_player,
+            PlayerId::Player2,
+            "Black has no piece left"
+        );
+
+        // When
+        board.undo();
+
+        // Then
+        assert_eq!(board.current_player, PlayerId::Player2);
+        assert_eq!(board.cell(1, 0), Some(&Piece(Black)));
+        assert_eq!(board.cell(2, 0), Some(&Empty));
+    }
+
+    #[test]
+    fn should_replay_move_when_redoing() {
+        // Given
+        let mut board = Board::default();
+        board.place(3, 2).expect("Move should be legal");
+        board.undo();
+
+        // When
+        let result = board.redo();
+
+        // Then
+        assert_eq!(result, Some(Move::Play(3, 2)));
+        assert_eq!(board.cell(3, 2), Some(&Piece(Black)));
+        assert_eq!(board.cell(3, 3), Some(&Piece(Black)));
+        assert_eq!(board.current_player, PlayerId::Player2);
+        assert_eq!(board.history(), vec![Move::Play(3, 2)]);
+    }
+
+    #[test]
+    fn should_forget_undone_moves_when_placing_a_new_move() {
+        // Given
+        let mut board = Board::default();
+        board.place(3, 2).expect("Move should be legal");
+        board.undo();
+
+        // When
+        board.place(2, 3).expect("Move should be legal");
+
+        // Then
+        assert!(!board.can_redo());
+        assert_eq!(board.history(), vec![Move::Play(2, 3)]);
+    }
+
+    #[test]
+    fn should_undo_and_redo_whole_random_games() {
+        for seed in 0..20 {
+            // Given
+            let mut rng = fastrand::Rng::with_seed(seed);
+            let mut board = Board::default();
+            let mut states = Vec::new();
+            while board.end_of_game().is_none() {
+                states.push((board.black, board.white, board.current_player));
+                let moves = board.available_positions(board.current_player());
+                let (x, y) = moves[rng.usize(0..moves.len())];
+                board.place(x, y).expect("Move should be legal");
+            }
+            let final_state = (board.black, board.white, board.current_player);
+
+            // When / Then
+            while let Some(state) = states.pop() {
+                board.undo();
+                assert_eq!((board.black, board.white, board.current_player), state);
+            }
+            assert!(!board.can_undo());
+            while board.redo().is_some() {}
+            assert_eq!(
+                (board.black, board.white, board.current_player),
+                final_state
+            );
+        }
+    }
+
+    fn hash_from_scratch(board: &Board) -> u64 {
+        zobrist::hash(board.black, board.white, board.current_player().color())
+    }
+
+    #[test]
+    fn should_keep_hash_up_to_date_when_playing_undoing_and_redoing() {
+        for seed in 0..20 {
+            // Given
+            let mut rng = fastrand::Rng::with_seed(seed);
+            let mut board = Board::default();
+
+            // When / Then
+            while board.end_of_game().is_none() {
+                let moves = board.available_positions(board.current_player());
+                let (x, y) = moves[rng.usize(0..moves.len())];
+                board.place(x, y).expect("Move should be legal");
+                assert_eq!(board.hash(), hash_from_scratch(&board));
+            }
+            while board.undo().is_some() {
+                assert_eq!(board.hash(), hash_from_scratch(&board));
+            }
+            assert_eq!(board.hash(), Board::default().hash());
+            while board.redo().is_some() {
+                assert_eq!(board.hash(), hash_from_scratch(&board));
+            }
+        }
+    }
+
+    #[test]
+    fn should_give_same_hash_to_transposed_positions() {
+        // Given
+        let first = Board::from_transcript("d3c3f5f6").expect("Transcript should be legal");
+        let second = Board::from_transcript("f5f6d3c3").expect("Transcript should be legal");
+
+        // When / Then
+        assert_eq!(first.to_string(), second.to_string());
+        assert_eq!(first.hash(), second.hash());
+        assert_ne!(first.hash(), Board::default().hash());
+    }
+
+    /// Black has no move, whoever is to move, and none left either once white takes `c1`.
+    fn board_where_black_must_pass_after_white_plays(color_to_move: char) -> Board {
+        format!("OX-----O-------X{} {}", "-".repeat(48), color_to_move)
+            .parse()
+            .expect("Position should be valid")
+    }
+
+    #[test]
+    fn should_report_pass_when_opponent_has_no_move() {
+        // Given
+        let mut board = board_where_black_must_pass_after_white_plays('O');
+
+        // When
+        let result = board.play(Move::Play(2, 0));
+
+        // Then
+        let outcome = result.expect("Move should be legal");
+        assert_eq!(outcome.flipped(), &[(1, 0)]);
+        assert_eq!(outcome.pass(), Some(Black));
+        assert_eq!(board.current_player, PlayerId::Player2);
+        assert_eq!(board.history(), vec![Move::Play(2, 0), Move::Pass]);
+    }
+
+    #[test]
+    fn should_not_report_pass_when_game_is_over() {
+        // Given
+        let mut board: Board = format!("OX{} O", "-".repeat(62))
+            .parse()
+            .expect("Position should be valid");
+
+        // When
+        let result = board.play(Move::Play(2, 0));
+
+        // Then
+        assert_eq!(result.expect("Move should be legal").pass(), None);
+        assert!(board.end_of_game().is_some());
+        assert_eq!(board.history(), vec![Move::Play(2, 0)]);
+    }
+
+    #[test]
+    fn should_not_report_pass_when_opponent_can_move() {
+        // Given
+        let mut board = Board::default();
+
+        // When
+        let result = board.play(Move::Play(3, 2));
+
+        // Then
+        assert_eq!(result.expect("Move should be legal").pass(), None);
+    }
+
+    #[test]
+    fn should_refuse_pass_when_a_move_is_available() {
+        // Given
+        let mut board = Board::default();
+
+        // When
+        let result = board.play(Move::Pass);
+
+        // Then
+        assert_eq!(result.err(), Some(MoveError::MustPlay));
+        assert_eq!(board.current_player, PlayerId::Player1);
+    }
+
+    #[test]
+    fn should_accept_pass_when_no_move_is_available() {
+        // Given
+        let mut board = board_where_black_must_pass_after_white_plays('X');
+        let black = board.black;
+
+        // When
+        let result = board.play(Move::Pass);
+
+        // Then
+        assert!(result.is_ok());
+        assert_eq!(board.black, black);
+        assert_eq!(board.current_player, PlayerId::Player2);
+        assert_eq!(board.history(), vec![Move::Pass]);
+        assert_eq!(board.undo(), Some(Move::Pass));
+        assert_eq!(board.current_player, PlayerId::Player1);
     }
 }
@@ -0,0 +1,337 @@
+//! Opening book: moves known from played games, with their results.
+//!
+//! A book is written one line per game or opening line: a transcript, optionally followed by
+//! the number of black wins, white wins and draws of the games which played it. Empty lines
+//! and lines starting with `#` are ignored.
+//!
+//! ```text
+//! f5d6c3d3c4f4f6f3e6e7 12 7 1
+//! f5f6e6f4e3
+//! ```
+
+use crate::domain::board::ColorPiece::{Black, White};
+use crate::domain::board::{Board, ColorPiece};
+use crate::domain::moves::Move;
+use crate::domain::notation::{NotationError, parse_transcript};
+use crate::domain::symmetry::Symmetry;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+const BUILTIN_BOOK: &str = include_str!("../../books/openings.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BookError {
+    /// The transcript of this line (counted from 1) cannot be played.
+    Transcript { line: usize, error: NotationError },
+    /// The results of this line are not three counts.
+    Results { line: usize, text: String },
+}
+
+impl fmt::Display for BookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BookError::Transcript { line, error } => write!(f, "line {}: {}", line, error),
+            BookError::Results { line, text } => {
+                write!(f, "line {}: invalid results \"{}\"", line, text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BookError {}
+
+/// Move of the book with the results of the games which played it.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct BookMove {
+    position: (usize, usize),
+    black_wins: u32,
+    white_wins: u32,
+    draws: u32,
+}
+
+impl BookMove {
+    pub fn position(&self) -> (usize, usize) {
+        self.position
+    }
+
+    pub fn games(&self) -> u32 {
+        self.black_wins + self.white_wins + self.draws
+    }
+
+    pub fn wins(&self, color: ColorPiece) -> u32 {
+        match color {
+            Black => self.black_wins,
+            White => self.white_wins,
+        }
+    }
+
+    pub fn draws(&self) -> u32 {
+        self.draws
+    }
+
+    /// Chance of the move to be chosen by `color`: one, plus two per win and one per draw, so
+    /// that a line without results can still be played.
+    pub fn weight(&self, color: ColorPiece) -> u64 {
+        1 + 2 * u64::from(self.wins(color)) + u64::from(self.draws)
+    }
+}
+
+/// Book moves of each position, found under any of its symmetries.
+#[derive(Default)]
+pub struct OpeningBook {
+    positions: HashMap<u64, Vec<BookMove>>,
+}
+
+impl OpeningBook {
+    /// Openings shipped with the game.
+    pub fn builtin() -> OpeningBook {
+        BUILTIN_BOOK.parse().expect("Builtin book should be valid")
+    }
+
+    /// Number of positions in the book.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Adds the moves of a line played from the initial position, each one with the results
+    /// of the line. An illegal line leaves the book unchanged.
+    pub fn add_line(
+        &mut self,
+        transcript: &str,
+        black_wins: u32,
+        white_wins: u32,
+        draws: u32,
+    ) -> Result<(), NotationError> {
+        let mut board = Board::default();
+        let mut played = Vec::new();
+        for (index, mv) in parse_transcript(transcript)?.into_iter().enumerate() {
+            // Passes are played automatically
+            let Move::Play(x, y) = mv else {
+                continue;
+            };
+            let hash = board.hash();
+            board
+                .play(mv)
+                .map_err(|reason| NotationError::IllegalMove { index, mv, reason })?;
+            played.push((hash, (x, y)));
+        }
+
+        for (hash, position) in played {
+            let moves = self.positions.entry(hash).or_default();
+            match moves
+                .iter_mut()
+                .find(|book_move| book_move.position == position)
+            {
+                Some(book_move) => {
+                    book_move.black_wins += black_wins;
+                    book_move.white_wins += white_wins;
+                    book_move.draws += draws;
+                }
+                None => moves.push(BookMove {
+                    position,
+                    black_wins,
+                    white_wins,
+                    draws,
+                }),
+            }
+        }
+        Ok(())
+    }
+
+    /// Book moves of the position, whichever symmetry of it the book knows.
+    pub fn moves(&self, board: &Board) -> Vec<BookMove> {
+        let mut found: Vec<BookMove> = Vec::new();
+        for symmetry in Symmetry::ALL {
+            let image = symmetry.apply_board(board);
+            let Some(moves) = self.positions.get(&image.hash()) else {
+                continue;
+            };
+            for book_move in moves {
+                let book_move = BookMove {
+                    position: symmetry.inverse().apply(book_move.position),
+                    ..*book_move
+                };
+                // A symmetrical position finds the same move again through another symmetry
+                match found
+                    .iter_mut()
+                    .find(|other| other.position == book_move.position)
+                {
+                    Some(other) if other.games() < book_move.games() => *other = book_move,
+                    Some(_) => {}
+                    None => found.push(book_move),
+                }
+            }
+        }
+        found.sort_by_key(|book_move| book_move.position);
+        found
+    }
+
+    /// Book move chosen at random for the player to move, in proportion to its weight.
+    pub fn choose(&self, board: &Board, rng: &mut fastrand::Rng) -> Option<(usize, usize)> {
+        let color = board.current_player().color();
+        let moves = self.moves(board);
+        let total: u64 = moves.iter().map(|book_move| book_move.weight(color)).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut draw = rng.u64(0..total);
+        for book_move in moves {
+            let weight = book_move.weight(color);
+            if draw < weight {
+                return Some(book_move.position);
+            }
+            draw -= weight;
+        }
+        None
+    }
+}
+
+impl FromStr for OpeningBook {
+    type Err = BookError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut book = OpeningBook::default();
+        for (index, text) in s.lines().enumerate() {
+            let line = index + 1;
+            let text = text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let mut fields = text.split_whitespace();
+            let transcript = fields.next().unwrap_or_default();
+            let results: Vec<&str> = fields.collect();
+            let invalid_results = || BookError::Results {
+                line,
+                text: results.join(" "),
+            };
+            let (black_wins, white_wins, draws) = match results[..] {
+                [] => (0, 0, 0),
+                [black, white, draws] => match (black.parse(), white.parse(), draws.parse()) {
+                    (Ok(black), Ok(white), Ok(draws)) => (black, white, draws),
+                    _ => return Err(invalid_results()),
+                },
+                _ => return Err(invalid_results()),
+            };
+            book.add_line(transcript, black_wins, white_wins, draws)
+                .map_err(|error| BookError::Transcript { line, error })?;
+        }
+        Ok(book)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_load_builtin_book() {
+        // When
+        let book = OpeningBook::builtin();
+
+        // Then
+        assert!(!book.is_empty());
+        assert_eq!(book.moves(&Board::default()).len(), 4);
+    }
+
+    #[test]
+    fn should_find_moves_of_symmetrical_position() {
+        // Given
+        let book: OpeningBook = "f5d6c3 3 1 0\nf5f6 0 2 0"
+            .parse()
+            .expect("Book should be valid");
+        // d3 is f5 reflected across the h1-a8 diagonal, which sends d6 to c5 and f6 to c3
+        let board = Board::from_transcript("d3").expect("Transcript should be legal");
+
+        // When
+        let moves = book.moves(&board);
+
+        // Then
+        let positions: Vec<(usize, usize)> = moves.iter().map(BookMove::position).collect();
+        assert_eq!(positions, vec![(2, 2), (2, 4)]);
+        assert_eq!(moves[0].wins(White), 2);
+        assert_eq!(moves[1].wins(Black), 3);
+    }
+
+    #[test]
+    fn should_sum_results_of_lines_sharing_a_move() {
+        // Given
+        let book: OpeningBook = "f5d6c3 3 1 0\n# comment\n\nf5d6c5 1 1 2"
+            .parse()
+            .expect("Book should be valid");
+        let board = Board::from_transcript("f5").expect("Transcript should be legal");
+
+        // When
+        let moves = book.moves(&board);
+
+        // Then
+        assert_eq!(moves.len(), 1);
+        assert_eq!(moves[0].position(), (3, 5));
+        assert_eq!(moves[0].games(), 8);
+        assert_eq!(moves[0].weight(White), 1 + 2 * 2 + 2);
+    }
+
+    #[test]
+    fn should_choose_only_book_moves() {
+        // Given
+        let book = OpeningBook::builtin();
+        let board = Board::from_transcript("f5").expect("Transcript should be legal");
+        let positions: Vec<(usize, usize)> =
+            book.moves(&board).iter().map(BookMove::position).collect();
+        let mut rng = fastrand::Rng::with_seed(2);
+
+        for _ in 0..20 {
+            // When
+            let choice = book.choose(&board, &mut rng);
+
+            // Then
+            assert!(positions.contains(&choice.expect("A move should be chosen")));
+        }
+    }
+
+    #[test]
+    fn should_not_choose_out_of_book() {
+        // Given
+        let book = OpeningBook::builtin();
+        let board =
+            Board::from_transcript("f5d6c3d3c4f4f6f3e6e7").expect("Transcript should be legal");
+
+        // When / Then
+        assert_eq!(book.choose(&board, &mut fastrand::Rng::new()), None);
+    }
+
+    #[test]
+    fn should_leave_book_unchanged_by_illegal_line() {
+        // Given
+        let mut book = OpeningBook::default();
+
+        // When
+        let result = book.add_line("f5d6c3f5", 1, 0, 0);
+
+        // Then
+        assert!(matches!(
+            result,
+            Err(NotationError::IllegalMove { index: 3, .. })
+        ));
+        assert!(book.is_empty());
+    }
+
+    #[test]
+    fn should_refuse_invalid_lines() {
+        assert!(matches!(
+            "f5d6\nf5f5".parse::<OpeningBook>().err(),
+            Some(BookError::Transcript { line: 2, .. })
+        ));
+        assert_eq!(
+            "f5d6 1 2".parse::<OpeningBook>().err(),
+            Some(BookError::Results {
+                line: 1,
+                text: "1 2".to_string()
+            })
+        );
+    }
+}
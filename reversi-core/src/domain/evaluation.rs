@@ -0,0 +1,201 @@
+//! Static evaluation of a position, without looking ahead.
+
+use crate::domain::bitboard;
+use crate::domain::board::ColorPiece::{Black, White};
+use crate::domain::board::{Board, ColorPiece};
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Value of each cell, from `a1` to `h8`. Corners are worth a lot, the cells next to them
+/// (C-squares on the edges, X-squares on the diagonals) give the corner away.
+#[rustfmt::skip]
+const SQUARE_WEIGHTS: [i32; 64] = [
+    100, -20,  10,   5,   5,  10, -20, 100,
+    -20, -50,  -2,  -2,  -2,  -2, -50, -20,
+     10,  -2,   1,   1,   1,   1,  -2,  10,
+      5,  -2,   1,   0,   0,   1,  -2,   5,
+      5,  -2,   1,   0,   0,   1,  -2,   5,
+     10,  -2,   1,   1,   1,   1,  -2,  10,
+    -20, -50,  -2,  -2,  -2,  -2, -50, -20,
+    100, -20,  10,   5,   5,  10, -20, 100,
+];
+const MOBILITY_WEIGHT: i32 = 8;
+const POTENTIAL_MOBILITY_WEIGHT: i32 = 3;
+const FRONTIER_WEIGHT: i32 = -4;
+const STABILITY_WEIGHT: i32 = 12;
+const PARITY_WEIGHT: i32 = 5;
+
+/// Terms of an evaluation, each one already weighted and positive when it favours the
+/// evaluated color.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Evaluation {
+    squares: i32,
+    mobility: i32,
+    potential_mobility: i32,
+    frontier: i32,
+    stability: i32,
+    parity: i32,
+}
+
+impl Evaluation {
+    /// Value of the occupied cells.
+    pub fn squares(&self) -> i32 {
+        self.squares
+    }
+
+    /// Difference in the number of legal moves.
+    pub fn mobility(&self) -> i32 {
+        self.mobility
+    }
+
+    /// Difference in the number of empty cells next to an opponent piece, which may become
+    /// moves later.
+    pub fn potential_mobility(&self) -> i32 {
+        self.potential_mobility
+    }
+
+    /// Difference in the number of pieces next to an empty cell.
+    pub fn frontier(&self) -> i32 {
+        self.frontier
+    }
+
+    /// Difference in the number of pieces which can no longer be flipped.
+    pub fn stability(&self) -> i32 {
+        self.stability
+    }
+
+    /// Whether the evaluated color should play the last move of the game.
+    pub fn parity(&self) -> i32 {
+        self.parity
+    }
+
+    pub fn total(&self) -> i32 {
+        self.squares
+            + self.mobility
+            + self.potential_mobility
+            + self.frontier
+            + self.stability
+            + self.parity
+    }
+}
+
+#[cfg_attr(test, automock)]
+pub trait Evaluator: Send + Sync {
+    /// Evaluation of the position for `color`.
+    fn breakdown(&self, board: &Board, color: ColorPiece) -> Evaluation;
+
+    /// Score of the position for `color`, positive when it is ahead.
+    fn evaluate(&self, board: &Board, color: ColorPiece) -> i32 {
+        self.breakdown(board, color).total()
+    }
+}
+
+/// Evaluation on square weights, mobility, frontier, stability and parity.
+#[derive(Default)]
+pub struct PositionalEvaluator {}
+
+impl Evaluator for PositionalEvaluator {
+    fn breakdown(&self, board: &Board, color: ColorPiece) -> Evaluation {
+        let opponent_color = match color {
+            Black => White,
+            White => Black,
+        };
+        let player = board.pieces(color);
+        let opponent = board.pieces(opponent_color);
+        let empty = !(player | opponent);
+
+        let squares = bitboard::Squares(player)
+            .map(|(x, y)| SQUARE_WEIGHTS[y * 8 + x])
+            .sum::<i32>()
+            - bitboard::Squares(opponent)
+                .map(|(x, y)| SQUARE_WEIGHTS[y * 8 + x])
+                .sum::<i32>();
+        let mobility = count(board.legal_moves(color)) - count(board.legal_moves(opponent_color));
+        let potential_mobility = count(bitboard::neighbours(opponent) & empty)
+            - count(bitboard::neighbours(player) & empty);
+        let frontier_cells = bitboard::neighbours(empty);
+        let frontier = count(player & frontier_cells) - count(opponent & frontier_cells);
+        let stability =
+            count(bitboard::stable(player, opponent)) - count(bitboard::stable(opponent, player));
+        // Without passes, the player to move plays the last move when the number of empty cells
+        // is odd
+        let plays_last = (count(empty) % 2 == 1) == (board.current_player().color() == color);
+        let parity = if plays_last { 1 } else { -1 };
+
+        Evaluation {
+            squares,
+            mobility: MOBILITY_WEIGHT * mobility,
+            potential_mobility: POTENTIAL_MOBILITY_WEIGHT * potential_mobility,
+            frontier: FRONTIER_WEIGHT * frontier,
+            stability: STABILITY_WEIGHT * stability,
+            parity: PARITY_WEIGHT * parity,
+        }
+    }
+}
+
+fn count(bitboard: u64) -> i32 {
+    bitboard.count_ones() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_give_opposite_scores_to_both_colors() {
+        let mut rng = fastrand::Rng::with_seed(11);
+        let evaluator = PositionalEvaluator::default();
+        let mut board = Board::default();
+        while board.end_of_game().is_none() {
+            // When
+            let black = evaluator.evaluate(&board, Black);
+            let white = evaluator.evaluate(&board, White);
+
+            // Then
+            assert_eq!(black, -white);
+
+            let moves = board.available_positions(board.current_player());
+            let (x, y) = moves[rng.usize(0..moves.len())];
+            board.place(x, y).expect("Move should be legal");
+        }
+    }
+
+    #[test]
+    fn should_only_count_parity_on_initial_position() {
+        // Given
+        let board = Board::default();
+
+        // When
+        let evaluation = PositionalEvaluator::default().breakdown(&board, Black);
+
+        // Then
+        assert_eq!(
+            evaluation,
+            Evaluation {
+                parity: -PARITY_WEIGHT,
+                ..Evaluation::default()
+            }
+        );
+    }
+
+    #[test]
+    fn should_value_corner_and_stable_pieces() {
+        // Given
+        let board: Board = format!("XXO{}O-------- O", "-".repeat(52))
+            .parse()
+            .expect("Position should be valid");
+
+        // When
+        let evaluation = PositionalEvaluator::default().breakdown(&board, Black);
+
+        // Then
+        assert_eq!(evaluation.squares(), (100 - 20) - (10 - 20));
+        assert_eq!(evaluation.stability(), 2 * STABILITY_WEIGHT);
+        assert_eq!(
+            evaluation.mobility(),
+            MOBILITY_WEIGHT,
+            "only black can play d1"
+        );
+    }
+}
@@ -0,0 +1,572 @@
+//! Generic Game Format, used by online Othello servers to record games:
+//!
+//! `(;GM[Othello]PC[NBoard]PB[alice]PW[bob]RB[1800]RW[1750]TI[5:00]TY[8]RE[+4.000]`
+//! `BO[8 ---------------------------O*------*O--------------------------- *]`
+//! `B[f5//1.2]W[d6/-2.00/0.8]...;)`
+//!
+//! Only 8x8 boards are supported. Moves may carry the evaluation of their player and the time
+//! they took, in seconds. A `]` or `\` inside a value is escaped with `\`.
+
+use crate::domain::board::ColorPiece::{Black, White};
+use crate::domain::board::{Board, ColorPiece};
+use crate::domain::moves::{Move, MoveError};
+use crate::domain::position::PositionError;
+use std::fmt;
+use std::str::FromStr;
+
+const INITIAL_POSITION: &str = "---------------------------OX------XO--------------------------- X";
+
+#[derive(Debug, PartialEq)]
+pub enum GgfError {
+    /// No game starting with `(;` was found.
+    MissingGame,
+    /// The game, or the value starting at this byte offset, is never closed.
+    Unterminated { offset: usize },
+    /// A property name was expected at this byte offset.
+    Malformed { offset: usize, found: char },
+    /// The game is not Othello (`GM` property).
+    UnsupportedGame(String),
+    /// The board is not the standard 8x8 one (`TY` or `BO` property).
+    UnsupportedBoard(String),
+    /// The start position at this byte offset cannot be read.
+    InvalidPosition { offset: usize, error: PositionError },
+    /// The start position of a game built by hand, not read from a text, cannot be read.
+    InvalidStart(PositionError),
+    /// The value at this byte offset is not a move, a rating, an evaluation or a time.
+    InvalidValue { offset: usize, text: String },
+    /// The move at this index of the move list cannot be played.
+    IllegalMove {
+        index: usize,
+        mv: Move,
+        reason: MoveError,
+    },
+    /// The move at this index is not played by the color to move.
+    WrongColor { index: usize },
+}
+
+impl fmt::Display for GgfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GgfError::MissingGame => write!(f, "no game found"),
+            GgfError::Unterminated { offset } => {
+                write!(f, "unterminated value at offset {}", offset)
+            }
+            GgfError::Malformed { offset, found } => {
+                write!(f, "unexpected '{}' at offset {}", found, offset)
+            }
+            GgfError::UnsupportedGame(game) => write!(f, "unsupported game \"{}\"", game),
+            GgfError::UnsupportedBoard(board) => write!(f, "unsupported board \"{}\"", board),
+            GgfError::InvalidPosition { offset, error } => {
+                write!(f, "invalid position at offset {}: {}", offset, error)
+            }
+            GgfError::InvalidStart(error) => write!(f, "invalid start position: {}", error),
+            GgfError::InvalidValue { offset, text } => {
+                write!(f, "invalid value \"{}\" at offset {}", text, offset)
+            }
+            GgfError::IllegalMove { index, mv, reason } => {
+                write!(f, "illegal move {} at index {}: {}", mv, index, reason)
+            }
+            GgfError::WrongColor { index } => {
+                write!(f, "move at index {} is played out of turn", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GgfError {}
+
+/// Move of a recorded game.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct GgfMove {
+    pub color: ColorPiece,
+    pub mv: Move,
+    /// Evaluation of the position by the player, in discs.
+    pub eval: Option<f32>,
+    /// Time spent on the move, in seconds.
+    pub time: Option<f32>,
+}
+
+/// Game in the Generic Game Format.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct GgfGame {
+    /// Where the game was played (`PC`).
+    pub place: Option<String>,
+    /// When the game was played (`DT`), as written by the server.
+    pub date: Option<String>,
+    pub black: Option<String>,
+    pub white: Option<String>,
+    pub black_rating: Option<f32>,
+    pub white_rating: Option<f32>,
+    /// Time control (`TI`), such as `5:00//2:00`.
+    pub time_control: Option<String>,
+    /// Result (`RE`), such as `+4.000` when black wins by 4 discs.
+    pub result: Option<String>,
+    /// Start position, in the notation of [`crate::domain::position`].
+    pub start: String,
+    /// Moves in the order they were played, passes included.
+    pub moves: Vec<GgfMove>,
+}
+
+impl Default for GgfGame {
+    fn default() -> Self {
+        Self {
+            place: None,
+            date: None,
+            black: None,
+            white: None,
+            black_rating: None,
+            white_rating: None,
+            time_control: None,
+            result: None,
+            start: INITIAL_POSITION.to_string(),
+            moves: Vec::new(),
+        }
+    }
+}
+
+impl GgfGame {
+    /// Final position of the game, each move played with `Board::place`.
+    pub fn replay(&self) -> Result<Board, GgfError> {
+        let mut board: Board = self.start.parse().map_err(GgfError::InvalidStart)?;
+        // The board plays the passes on its own once the game has started: a recorded pass is
+        // either one it already played or one of the start position, which may be left out
+        let mut passed = None;
+        for (index, ggf_move) in self.moves.iter().enumerate() {
+            let illegal = |reason| GgfError::IllegalMove {
+                index,
+                mv: ggf_move.mv,
+                reason,
+            };
+            match ggf_move.mv {
+                Move::Pass if passed == Some(ggf_move.color) => passed = None,
+                Move::Pass => {
+                    if board.current_player().color() != ggf_move.color {
+                        return Err(GgfError::WrongColor { index });
+                    }
+                    board.play(Move::Pass).map_err(illegal)?;
+                }
+                Move::Play(x, y) => {
+                    if board.current_player().color() != ggf_move.color
+                        && board.play(Move::Pass).is_err()
+                    {
+                        return Err(GgfError::WrongColor { index });
+                    }
+                    let outcome = board.play(Move::Play(x, y)).map_err(illegal)?;
+                    passed = outcome.pass();
+                }
+            }
+        }
+        Ok(board)
+    }
+}
+
+/// Every game of a file, one after the other.
+pub fn parse_games(text: &str) -> Result<Vec<GgfGame>, GgfError> {
+    let mut games = Vec::new();
+    let mut offset = 0;
+    while let Some(start) = text[offset..].find("(;") {
+        let (game, end) = parse_game(text, offset + start)?;
+        games.push(game);
+        offset = end;
+    }
+    Ok(games)
+}
+
+impl FromStr for GgfGame {
+    type Err = GgfError;
+
+    /// First game of the text.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let start = s.find("(;").ok_or(GgfError::MissingGame)?;
+        parse_game(s, start).map(|(game, _)| game)
+    }
+}
+
+/// Reads the game starting with `(;` at `start`, and gives the offset right after it.
+fn parse_game(text: &str, start: usize) -> Result<(GgfGame, usize), GgfError> {
+    let mut game = GgfGame::default();
+    let mut chars = text[start + 2..]
+        .char_indices()
+        .map(|(index, c)| (start + 2 + index, c))
+        .peekable();
+    loop {
+        let Some((offset, c)) = chars.next() else {
+            return Err(GgfError::Unterminated { offset: start });
+        };
+        if c.is_whitespace() {
+            continue;
+        }
+        if c == ';' && chars.peek().map(|(_, c)| *c) == Some(')') {
+            let (end, _) = chars.next().expect("Closing parenthesis should follow");
+            return Ok((game, end + 1));
+        }
+        if !c.is_ascii_uppercase() {
+            return Err(GgfError::Malformed { offset, found: c });
+        }
+
+        let mut name = c.to_string();
+        while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_uppercase()) {
+            name.push(c);
+        }
+        match chars.next() {
+            Some((_, '[')) => {}
+            Some((offset, found)) => return Err(GgfError::Malformed { offset, found }),
+            None => return Err(GgfError::Unterminated { offset: start }),
+        }
+        let value_offset = chars.peek().map_or(text.len(), |(offset, _)| *offset);
+        let mut value = String::new();
+        loop {
+            match chars.next() {
+                Some((_, ']')) => break,
+                Some((_, '\\')) => match chars.next() {
+                    Some((_, c)) => value.push(c),
+                    None => {
+                        return Err(GgfError::Unterminated {
+                            offset: value_offset,
+                        });
+                    }
+                },
+                Some((_, c)) => value.push(c),
+                None => {
+                    return Err(GgfError::Unterminated {
+                        offset: value_offset,
+                    });
+                }
+            }
+        }
+        apply_property(&mut game, &name, value, value_offset)?;
+    }
+}
+
+fn apply_property(
+    game: &mut GgfGame,
+    name: &str,
+    value: String,
+    offset: usize,
+) -> Result<(), GgfError> {
+    let number = |text: &str| -> Result<Option<f32>, GgfError> {
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        text.trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| GgfError::InvalidValue {
+                offset,
+                text: text.to_string(),
+            })
+    };
+    match name {
+        "GM" if !value.eq_ignore_ascii_case("othello") => {
+            return Err(GgfError::UnsupportedGame(value));
+        }
+        "TY" if value.trim() != "8" => return Err(GgfError::UnsupportedBoard(value)),
+        "PC" => game.place = Some(value),
+        "DT" => game.date = Some(value),
+        "PB" => game.black = Some(value),
+        "PW" => game.white = Some(value),
+        "RB" => game.black_rating = number(&value)?,
+        "RW" => game.white_rating = number(&value)?,
+        "TI" => game.time_control = Some(value),
+        "RE" => game.result = Some(value),
+        "BO" => {
+            let position = value
+                .trim()
+                .strip_prefix("8 ")
+                .ok_or_else(|| GgfError::UnsupportedBoard(value.clone()))?;
+            let board: Board = position
+                .parse()
+                .map_err(|error| GgfError::InvalidPosition { offset, error })?;
+            game.start = board.to_string();
+        }
+        "B" | "W" => {
+            let mut fields = value.split('/');
+            let text = fields.next().unwrap_or_default().trim();
+            let mv = text
+                .to_ascii_lowercase()
+                .parse()
+                .map_err(|_| GgfError::InvalidValue {
+                    offset,
+                    text: text.to_string(),
+                })?;
+            game.moves.push(GgfMove {
+                color: if name == "B" { Black } else { White },
+                mv,
+                eval: number(fields.next().unwrap_or_default())?,
+                time: number(fields.next().unwrap_or_default())?,
+            });
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace(']', "\\]")
+}
+
+impl fmt::Display for GgfGame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(;GM[Othello]")?;
+        let texts = [
+            ("PC", &self.place),
+            ("DT", &self.date),
+            ("PB", &self.black),
+            ("PW", &self.white),
+        ];
+        for (name, value) in texts {
+            if let Some(value) = value {
+                write!(f, "{}[{}]", name, escape(value))?;
+            }
+        }
+        for (name, rating) in [("RB", self.black_rating), ("RW", self.white_rating)] {
+            if let Some(rating) = rating {
+                write!(f, "{}[{}]", name, rating)?;
+            }
+        }
+        if let Some(time_control) = &self.time_control {
+            write!(f, "TI[{}]", escape(time_control))?;
+        }
+        write!(f, "TY[8]")?;
+        if let Some(result) = &self.result {
+            write!(f, "RE[{}]", escape(result))?;
+        }
+
+        // The position notation uses the same cells, GGF writes black as `*`
+        let (cells, color) = self.start.split_once(' ').unwrap_or((&self.start, "X"));
+        let to_ggf = |c: char| match c {
+            'X' => '*',
+            other => other,
+        };
+        write!(
+            f,
+            "BO[8 {} {}]",
+            cells.chars().map(to_ggf).collect::<String>(),
+            color.chars().map(to_ggf).collect::<String>()
+        )?;
+
+        for ggf_move in &self.moves {
+            let name = match ggf_move.color {
+                Black => "B",
+                White => "W",
+            };
+            write!(f, "{}[{}", name, ggf_move.mv)?;
+            if ggf_move.eval.is_some() || ggf_move.time.is_some() {
+                let number = |value: Option<f32>| value.map(|v| v.to_string()).unwrap_or_default();
+                write!(f, "/{}/{}", number(ggf_move.eval), number(ggf_move.time))?;
+            }
+            write!(f, "]")?;
+        }
+        write!(f, ";)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAME: &str = "(;GM[Othello]PC[NBoard]DT[2024.05.04_12:30:00.UTC]PB[alice]PW[bob]\
+        RB[1812.5]RW[1750]TI[5:00//2:00]TY[8]RE[+4.000]\
+        BO[8 ---------------------------O*------*O--------------------------- *]\
+        B[F5//1.25]W[d6/-2.00/0.5]B[c3];)";
+
+    #[test]
+    fn should_parse_game_with_metadata() {
+        // When
+        let game: GgfGame = GAME.parse().expect("Game should be valid");
+
+        // Then
+        assert_eq!(game.place.as_deref(), Some("NBoard"));
+        assert_eq!(game.black.as_deref(), Some("alice"));
+        assert_eq!(game.white.as_deref(), Some("bob"));
+        assert_eq!(game.black_rating, Some(1812.5));
+        assert_eq!(game.white_rating, Some(1750.0));
+        assert_eq!(game.time_control.as_deref(), Some("5:00//2:00"));
+        assert_eq!(game.result.as_deref(), Some("+4.000"));
+        assert_eq!(game.start, INITIAL_POSITION);
+        assert_eq!(
+            game.moves[1],
+            GgfMove {
+                color: White,
+                mv: Move::Play(3, 5),
+                eval: Some(-2.0),
+                time: Some(0.5),
+            }
+        );
+        assert_eq!(game.moves[0].eval, None);
+        assert_eq!(game.moves[0].time, Some(1.25));
+    }
+
+    #[test]
+    fn should_replay_moves() {
+        // Given
+        let game: GgfGame = GAME.parse().expect("Game should be valid");
+
+        // When
+        let board = game.replay().expect("Game should be legal");
+
+        // Then
+        let expected = Board::from_transcript("f5d6c3").expect("Transcript should be legal");
+        assert_eq!(board.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn should_write_game_read_back_the_same() {
+        // Given
+        let mut game: GgfGame = GAME.parse().expect("Game should be valid");
+        game.black = Some("a]b\\c".to_string());
+
+        // When
+        let text = game.to_string();
+
+        // Then
+        assert!(text.contains("PB[a\\]b\\\\c]"));
+        assert_eq!(text.parse::<GgfGame>(), Ok(game));
+    }
+
+    #[test]
+    fn should_accept_recorded_passes() {
+        // Given: after a1, white has no move left and black goes on with c8
+        let start = format!("-OXXXXXX{}XO{} *", "-".repeat(48), "-".repeat(6));
+        let text = format!("(;GM[Othello]BO[8 {}]B[a1]W[PA]B[c8];)", start);
+        let game: GgfGame = text.parse().expect("Game should be valid");
+
+        // When
+        let board = game.replay().expect("Game should be legal");
+
+        // Then
+        assert!(board.end_of_game().is_some());
+        assert_eq!(game.moves[1].mv, Move::Pass);
+    }
+
+    #[test]
+    fn should_accept_pass_of_start_position_written_or_not() {
+        // Given: black has no move, white takes c1 and every disc
+        let start = format!("O*{} *", "-".repeat(62));
+        let written: GgfGame = format!("(;GM[Othello]BO[8 {}]B[PA]W[c1];)", start)
+            .parse()
+            .expect("Game should be valid");
+        let left_out: GgfGame = format!("(;GM[Othello]BO[8 {}]W[c1];)", start)
+            .parse()
+            .expect("Game should be valid");
+
+        // When
+        let boards = [written.replay(), left_out.replay()];
+
+        // Then
+        for board in boards {
+            let score = board
+                .expect("Game should be legal")
+                .end_of_game()
+                .expect("Game should be over");
+            assert_eq!((score.player1(), score.player2()), (0, 3));
+        }
+    }
+
+    #[test]
+    fn should_refuse_pass_of_player_who_can_move() {
+        // Given
+        let game: GgfGame = "(;GM[Othello]B[PA];)"
+            .parse()
+            .expect("Game should be readable");
+
+        // When / Then
+        assert_eq!(
+            game.replay().err(),
+            Some(GgfError::IllegalMove {
+                index: 0,
+                mv: Move::Pass,
+                reason: MoveError::MustPlay
+            })
+        );
+    }
+
+    #[test]
+    fn should_parse_every_game_of_a_file() {
+        // Given
+        let text = format!("{}\n{}\n", GAME, GAME.replace("alice", "carol"));
+
+        // When
+        let games = parse_games(&text).expect("Games should be valid");
+
+        // Then
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[1].black.as_deref(), Some("carol"));
+    }
+
+    #[test]
+    fn should_report_where_a_record_is_malformed() {
+        assert_eq!("PB[alice]".parse::<GgfGame>(), Err(GgfError::MissingGame));
+        assert_eq!(
+            "(;GM[Othello]PB[alice".parse::<GgfGame>(),
+            Err(GgfError::Unterminated { offset: 16 })
+        );
+        assert_eq!(
+            "(;GM[Othello]pb[alice];)".parse::<GgfGame>(),
+            Err(GgfError::Malformed {
+                offset: 13,
+                found: 'p'
+            })
+        );
+        assert_eq!(
+            "(;GM[Othello]B[z9];)".parse::<GgfGame>(),
+            Err(GgfError::InvalidValue {
+                offset: 15,
+                text: "z9".to_string()
+            })
+        );
+        assert_eq!(
+            "(;GM[Chess];)".parse::<GgfGame>(),
+            Err(GgfError::UnsupportedGame("Chess".to_string()))
+        );
+        assert_eq!(
+            "(;GM[Othello]TY[10];)".parse::<GgfGame>(),
+            Err(GgfError::UnsupportedBoard("10".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_report_illegal_moves_on_replay() {
+        // Given
+        let game: GgfGame = "(;GM[Othello]B[f5]W[a1];)"
+            .parse()
+            .expect("Game should be readable");
+
+        // When / Then
+        assert_eq!(
+            game.replay().err(),
+            Some(GgfError::IllegalMove {
+                index: 1,
+                mv: Move::Play(0, 0),
+                reason: MoveError::NoFlip
+            })
+        );
+        let out_of_turn: GgfGame = "(;GM[Othello]W[f5];)"
+            .parse()
+            .expect("Game should be readable");
+        assert_eq!(
+            out_of_turn.replay().err(),
+            Some(GgfError::WrongColor { index: 0 })
+        );
+    }
+
+    #[test]
+    fn should_report_invalid_start_of_game_built_by_hand() {
+        // Given
+        let game = GgfGame {
+            start: "XO".to_string(),
+            ..GgfGame::default()
+        };
+
+        // When
+        let result = game.replay();
+
+        // Then
+        assert_eq!(
+            result.err(),
+            Some(GgfError::InvalidStart(PositionError::InvalidLength(2)))
+        );
+    }
+}
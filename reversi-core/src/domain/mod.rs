@@ -1,3 +1,15 @@
+mod bitboard;
 pub mod board;
+pub mod book;
 mod player;
-pub mod directions;
\ No newline at end of file
+pub mod directions;
+pub mod evaluation;
+pub mod ggf;
+pub mod moves;
+pub mod notation;
+pub mod perft;
+pub mod position;
+pub mod search;
+pub mod symmetry;
+pub mod wthor;
+mod zobrist;
\ No newline at end of file
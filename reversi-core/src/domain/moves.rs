@@ -0,0 +1,64 @@
+use crate::domain::board::ColorPiece;
+use std::fmt;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Move {
+    Play(usize, usize),
+    Pass,
+}
+
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub struct MoveOutcome {
+    flipped: Vec<(usize, usize)>,
+    pass: Option<ColorPiece>,
+}
+
+impl MoveOutcome {
+    pub(crate) fn new(flipped: Vec<(usize, usize)>, pass: Option<ColorPiece>) -> Self {
+        Self { flipped, pass }
+    }
+
+    pub fn flipped(&self) -> &[(usize, usize)] {
+        &self.flipped
+    }
+
+    pub fn into_flipped(self) -> Vec<(usize, usize)> {
+        self.flipped
+    }
+
+    /// Color of the player who had to pass right after this move, if any.
+    pub fn pass(&self) -> Option<ColorPiece> {
+        self.pass
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MoveError {
+    /// The position is outside the board.
+    OutOfBounds,
+    /// The cell already holds a piece.
+    Occupied,
+    /// The move would not flip any piece of the opponent.
+    NoFlip,
+    /// Neither player can move anymore.
+    GameOver,
+    /// The current player cannot pass while a move is available.
+    MustPlay,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            MoveError::OutOfBounds => "position is outside the board",
+            MoveError::Occupied => "cell is already occupied",
+            MoveError::NoFlip => "move does not flip any piece",
+            MoveError::GameOver => "game is over",
+            MoveError::MustPlay => "cannot pass while a move is available",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for MoveError {}
@@ -0,0 +1,271 @@
+//! Algebraic notation: columns `a` to `h` from left to right (`x`), rows `1` to `8` from top to
+//! bottom (`y`), so `f5` is the position `(5, 4)`. A transcript chains the moves of a game
+//! (`f5d6c3d3c4`), passes are implicit but `pa` is accepted.
+
+use crate::domain::board::Board;
+use crate::domain::moves::{Move, MoveError};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NotationError {
+    /// The text at this byte offset is not a coordinate.
+    Malformed { offset: usize, text: String },
+    /// The move at this index of the transcript cannot be played.
+    IllegalMove {
+        index: usize,
+        mv: Move,
+        reason: MoveError,
+    },
+}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotationError::Malformed { offset, text } => {
+                write!(f, "malformed move \"{}\" at offset {}", text, offset)
+            }
+            NotationError::IllegalMove { index, mv, reason } => {
+                write!(f, "illegal move {} at index {}: {}", mv, index, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for NotationError {}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Move::Play(x, y) => match u8::try_from(*x).ok().filter(|x| *x < 8) {
+                Some(column) if *y < 8 => write!(f, "{}{}", (b'a' + column) as char, y + 1),
+                // Off the board: shown as is rather than as the name of another cell
+                _ => write!(f, "({}, {})", x, y),
+            },
+            Move::Pass => write!(f, "pa"),
+        }
+    }
+}
+
+impl FromStr for Move {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || NotationError::Malformed {
+            offset: 0,
+            text: s.to_string(),
+        };
+        if s.eq_ignore_ascii_case("pa") {
+            return Ok(Move::Pass);
+        }
+        let bytes = s.as_bytes();
+        if bytes.len() != 2 {
+            return Err(malformed());
+        }
+        let column = bytes[0].to_ascii_lowercase();
+        let row = bytes[1];
+        if !(b'a'..=b'h').contains(&column) || !(b'1'..=b'8').contains(&row) {
+            return Err(malformed());
+        }
+        Ok(Move::Play((column - b'a') as usize, (row - b'1') as usize))
+    }
+}
+
+/// Splits a transcript into moves, ignoring whitespace between them.
+pub fn parse_transcript(transcript: &str) -> Result<Vec<Move>, NotationError> {
+    let mut moves = Vec::new();
+    let mut chars = transcript
+        .char_indices()
+        .filter(|(_, c)| !c.is_whitespace());
+    while let Some((offset, first)) = chars.next() {
+        let text = match chars.next() {
+            Some((_, second)) => format!("{}{}", first, second),
+            None => first.to_string(),
+        };
+        let mv = text
+            .parse()
+            .map_err(|_| NotationError::Malformed { offset, text })?;
+        moves.push(mv);
+    }
+    Ok(moves)
+}
+
+/// Writes moves one after the other, leaving the passes out.
+pub fn format_transcript(moves: &[Move]) -> String {
+    moves
+        .iter()
+        .filter(|mv| **mv != Move::Pass)
+        .map(Move::to_string)
+        .collect()
+}
+
+impl Board {
+    /// Replays a transcript from the initial position.
+    pub fn from_transcript(transcript: &str) -> Result<Board, NotationError> {
+        let mut board = Board::default();
+        board.play_transcript(transcript)?;
+        Ok(board)
+    }
+
+    /// Replays a transcript from the current position. A pass which was already applied
+    /// automatically after the previous move is skipped.
+    pub fn play_transcript(&mut self, transcript: &str) -> Result<(), NotationError> {
+        self.play_moves(&parse_transcript(transcript)?)
+    }
+
+    /// Plays moves from the current position, skipping the passes already applied
+    /// automatically like `play_transcript`.
+    pub fn play_moves(&mut self, moves: &[Move]) -> Result<(), NotationError> {
+        let mut pending_pass = false;
+        for (index, &mv) in moves.iter().enumerate() {
+            if mv == Move::Pass && pending_pass {
+                pending_pass = false;
+                continue;
+            }
+            let outcome =
+                self.play(mv)
+                    .map_err(|reason| NotationError::IllegalMove { index, mv, reason })?;
+            pending_pass = outcome.pass().is_some();
+        }
+        Ok(())
+    }
+
+    /// Moves played since the start of the game, as a transcript.
+    pub fn transcript(&self) -> String {
+        format_transcript(&self.history())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_moves() {
+        assert_eq!(Move::Play(5, 4).to_string(), "f5");
+        assert_eq!(Move::Play(0, 0).to_string(), "a1");
+        assert_eq!(Move::Play(7, 7).to_string(), "h8");
+        assert_eq!(Move::Pass.to_string(), "pa");
+    }
+
+    #[test]
+    fn should_format_coordinates_outside_the_board_as_is() {
+        assert_eq!(Move::Play(8, 0).to_string(), "(8, 0)");
+        assert_eq!(Move::Play(0, 8).to_string(), "(0, 8)");
+        assert_eq!(Move::Play(256, 0).to_string(), "(256, 0)");
+        assert_eq!(
+            Move::Play(0, usize::MAX).to_string(),
+            format!("(0, {})", usize::MAX)
+        );
+    }
+
+    #[test]
+    fn should_parse_moves_whatever_the_case() {
+        assert_eq!("f5".parse(), Ok(Move::Play(5, 4)));
+        assert_eq!("F5".parse(), Ok(Move::Play(5, 4)));
+        assert_eq!("PA".parse(), Ok(Move::Pass));
+    }
+
+    #[test]
+    fn should_refuse_coordinates_outside_the_board() {
+        assert!("i1".parse::<Move>().is_err());
+        assert!("a9".parse::<Move>().is_err());
+        assert!("a0".parse::<Move>().is_err());
+        assert!("a".parse::<Move>().is_err());
+    }
+
+    #[test]
+    fn should_parse_transcript_with_or_without_spaces() {
+        // Given
+        let transcript = "f5d6 C3\nd3";
+
+        // When
+        let result = parse_transcript(transcript);
+
+        // Then
+        assert_eq!(
+            result,
+            Ok(vec![
+                Move::Play(5, 4),
+                Move::Play(3, 5),
+                Move::Play(2, 2),
+                Move::Play(3, 2)
+            ])
+        );
+    }
+
+    #[test]
+    fn should_report_offset_of_malformed_move() {
+        // Given
+        let transcript = "f5d6z3";
+
+        // When
+        let result = parse_transcript(transcript);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(NotationError::Malformed {
+                offset: 4,
+                text: "z3".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn should_replay_transcript_on_board() {
+        // Given
+        let transcript = "f5d6c3d3c4";
+
+        // When
+        let board = Board::from_transcript(transcript).expect("Transcript should be legal");
+
+        // Then
+        assert_eq!(board.transcript(), transcript);
+        assert!(board.player2());
+    }
+
+    #[test]
+    fn should_report_index_of_illegal_move() {
+        // Given
+        let transcript = "f5a1";
+
+        // When
+        let result = Board::from_transcript(transcript);
+
+        // Then
+        assert_eq!(
+            result.err(),
+            Some(NotationError::IllegalMove {
+                index: 1,
+                mv: Move::Play(0, 0),
+                reason: MoveError::NoFlip
+            })
+        );
+    }
+
+    #[test]
+    fn should_round_trip_random_games() {
+        for seed in 0..20 {
+            // Given
+            let mut rng = fastrand::Rng::with_seed(seed);
+            let mut board = Board::default();
+            while board.end_of_game().is_none() {
+                let moves = board.available_positions(board.current_player());
+                let (x, y) = moves[rng.usize(0..moves.len())];
+                board.place(x, y).expect("Move should be legal");
+            }
+            let with_passes: String = board.history().iter().map(Move::to_string).collect();
+
+            // When
+            let replayed = Board::from_transcript(&board.transcript());
+            let replayed_with_passes = Board::from_transcript(&with_passes);
+
+            // Then
+            let replayed = replayed.expect("Transcript should be legal");
+            let replayed_with_passes = replayed_with_passes.expect("Transcript should be legal");
+            assert_eq!(replayed.history(), board.history());
+            assert_eq!(replayed_with_passes.history(), board.history());
+        }
+    }
+}
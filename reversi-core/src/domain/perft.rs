@@ -0,0 +1,127 @@
+//! Perft: counts the move sequences of a given length, to check the move generator against
+//! numbers computed by other programs.
+//!
+//! A forced pass counts as a move of its own, and a game over before the requested depth
+//! counts as one sequence, as in the usual Othello perft numbers.
+
+use crate::domain::board::Board;
+use crate::domain::moves::Move;
+
+/// Number of sequences of `depth` moves playable from the position.
+pub fn perft(board: &Board, depth: u32) -> u64 {
+    let mut board = board.clone();
+    count(&mut board, depth)
+}
+
+/// Perft of each move of the current player, the sequences starting with it.
+pub fn divide(board: &Board, depth: u32) -> Vec<((usize, usize), u64)> {
+    if depth == 0 {
+        return Vec::new();
+    }
+    let mut board = board.clone();
+    board
+        .available_positions(board.current_player())
+        .into_iter()
+        .map(|(x, y)| {
+            let nodes = count_after(&mut board, (x, y), depth);
+            ((x, y), nodes)
+        })
+        .collect()
+}
+
+fn count(board: &mut Board, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    let moves = board.available_positions(board.current_player());
+    if moves.is_empty() {
+        if board.end_of_game().is_some() {
+            return 1;
+        }
+        // The board passes by itself after a move, but not in a position given as is
+        board.play(Move::Pass).expect("Blocked player should pass");
+        let nodes = count(board, depth - 1);
+        board.undo();
+        return nodes;
+    }
+    moves
+        .into_iter()
+        .map(|position| count_after(board, position, depth))
+        .sum()
+}
+
+/// Sequences of `depth` moves starting with `position`.
+fn count_after(board: &mut Board, (x, y): (usize, usize), depth: u32) -> u64 {
+    let outcome = board
+        .play(Move::Play(x, y))
+        .expect("Available move should be legal");
+    // The board played the pass of the opponent already
+    let nodes = match (outcome.pass().is_some(), depth) {
+        (false, depth) => count(board, depth - 1),
+        (true, 1) => 1,
+        (true, depth) => count(board, depth - 2),
+    };
+    board.undo();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_match_known_counts_from_initial_position() {
+        // Given
+        let board = Board::default();
+        let expected = [1, 4, 12, 56, 244, 1396, 8200, 55092, 390216, 3005288];
+
+        for (depth, nodes) in expected.into_iter().enumerate() {
+            // When
+            let result = perft(&board, depth as u32);
+
+            // Then
+            assert_eq!(result, nodes, "depth {}", depth);
+        }
+    }
+
+    #[test]
+    fn should_count_pass_as_a_move() {
+        // Given: after a1 or c8, white has no move left and black plays the other one, which
+        // ends the game
+        let board: Board = format!("-OXXXXXX{}XO{} X", "-".repeat(48), "-".repeat(6))
+            .parse()
+            .expect("Position should be valid");
+
+        // When / Then
+        assert_eq!(perft(&board, 1), 2);
+        assert_eq!(perft(&board, 2), 2);
+        assert_eq!(perft(&board, 3), 2);
+        assert_eq!(perft(&board, 4), 2);
+    }
+
+    #[test]
+    fn should_pass_for_a_blocked_color_to_move() {
+        // Given: black cannot take the corner piece, white can flank either black piece
+        let board: Board = format!("OX------X{} X", "-".repeat(55))
+            .parse()
+            .expect("Position should be valid");
+
+        // When / Then
+        assert_eq!(perft(&board, 1), 1);
+        assert_eq!(perft(&board, 2), 2);
+    }
+
+    #[test]
+    fn should_divide_counts_between_first_moves() {
+        // Given
+        let board = Board::default();
+
+        // When
+        let result = divide(&board, 4);
+
+        // Then
+        assert_eq!(result.len(), 4);
+        assert!(result.iter().all(|(_, nodes)| *nodes == 61));
+        assert_eq!(result.iter().map(|(_, nodes)| nodes).sum::<u64>(), 244);
+    }
+}
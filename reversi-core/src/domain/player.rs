@@ -1,6 +1,7 @@
 use crate::domain::board::ColorPiece;
 use crate::domain::board::ColorPiece::{Black, White};
 
+#[derive(Clone)]
 pub struct Player(ColorPiece);
 
 impl Player {
@@ -0,0 +1,213 @@
+//! Text format of a position, as used by most Othello engines: the 64 cells from `a1` to `h8`
+//! row by row (`X` black, `O` white, `-` empty) followed by the color to move.
+//!
+//! `---------------------------OX------XO--------------------------- X`
+
+use crate::domain::bitboard;
+use crate::domain::board::ColorPiece::{Black, White};
+use crate::domain::board::{Board, Case, ColorPiece};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PositionError {
+    /// The position does not describe exactly 64 cells.
+    InvalidLength(usize),
+    /// The cell at this index (0 for `a1`, 63 for `h8`) is neither a piece nor empty.
+    InvalidCell { index: usize, found: char },
+    /// The color to move is missing or unknown.
+    InvalidColorToMove(String),
+}
+
+impl fmt::Display for PositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PositionError::InvalidLength(length) => {
+                write!(f, "expected 64 cells, found {}", length)
+            }
+            PositionError::InvalidCell { index, found } => {
+                write!(f, "invalid cell '{}' at index {}", found, index)
+            }
+            PositionError::InvalidColorToMove(text) => {
+                write!(f, "invalid color to move \"{}\"", text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PositionError {}
+
+fn parse_color(c: char) -> Option<Case> {
+    match c {
+        'X' | 'x' | '*' | 'B' | 'b' => Some(Case::Piece(Black)),
+        'O' | 'o' | '0' | 'W' | 'w' => Some(Case::Piece(White)),
+        '-' | '.' => Some(Case::Empty),
+        _ => None,
+    }
+}
+
+fn color_char(color: ColorPiece) -> char {
+    match color {
+        Black => 'X',
+        White => 'O',
+    }
+}
+
+impl FromStr for Board {
+    type Err = PositionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let end = s.find(char::is_whitespace).unwrap_or(s.len());
+        let cells: Vec<char> = s[..end].chars().collect();
+        if cells.len() != 64 {
+            return Err(PositionError::InvalidLength(cells.len()));
+        }
+
+        let mut black = 0;
+        let mut white = 0;
+        for (index, &c) in cells.iter().enumerate() {
+            let square = bitboard::square(index % 8, index / 8);
+            match parse_color(c) {
+                Some(Case::Piece(Black)) => black |= square,
+                Some(Case::Piece(White)) => white |= square,
+                Some(Case::Empty) => {}
+                None => return Err(PositionError::InvalidCell { index, found: c }),
+            }
+        }
+
+        let color_to_move = s[end..].trim();
+        let mut chars = color_to_move.chars();
+        let color = match (chars.next().and_then(parse_color), chars.next()) {
+            (Some(Case::Piece(color)), None) => color,
+            _ => return Err(PositionError::InvalidColorToMove(color_to_move.to_string())),
+        };
+        Ok(Board::with_position(black, white, color))
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for index in 0..64 {
+            let c = match self.cell(index % 8, index / 8) {
+                Some(Case::Piece(color)) => color_char(*color),
+                _ => '-',
+            };
+            write!(f, "{}", c)?;
+        }
+        write!(f, " {}", color_char(self.current_player().color()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INITIAL_POSITION: &str =
+        "---------------------------OX------XO--------------------------- X";
+
+    #[test]
+    fn should_format_initial_position() {
+        // Given
+        let board = Board::default();
+
+        // When
+        let result = board.to_string();
+
+        // Then
+        assert_eq!(result, INITIAL_POSITION);
+    }
+
+    #[test]
+    fn should_parse_initial_position() {
+        // When
+        let board: Board = INITIAL_POSITION.parse().expect("Position should be valid");
+
+        // Then
+        assert_eq!(board.cell(3, 3), Some(&Case::Piece(White)));
+        assert_eq!(board.cell(4, 3), Some(&Case::Piece(Black)));
+        assert_eq!(board.cell(3, 4), Some(&Case::Piece(Black)));
+        assert_eq!(board.cell(4, 4), Some(&Case::Piece(White)));
+        assert!(board.player1());
+        assert_eq!(
+            board.available_positions(board.current_player()),
+            Board::default().available_positions(Board::default().current_player())
+        );
+    }
+
+    #[test]
+    fn should_parse_white_to_move_with_alternative_characters() {
+        // Given
+        let position = format!("{}{}", "*".repeat(32), "O".repeat(31) + ". o");
+
+        // When
+        let board: Board = position.parse().expect("Position should be valid");
+
+        // Then
+        assert!(board.player2());
+        assert_eq!(board.cell(7, 3), Some(&Case::Piece(Black)));
+        assert_eq!(board.cell(7, 7), Some(&Case::Empty));
+        assert_eq!(
+            board.to_string(),
+            format!("{}{}-", "X".repeat(32), "O".repeat(31)) + " O"
+        );
+    }
+
+    #[test]
+    fn should_round_trip_positions_of_random_games() {
+        let mut rng = fastrand::Rng::with_seed(7);
+        let mut board = Board::default();
+        while board.end_of_game().is_none() {
+            // When
+            let parsed: Board = board.to_string().parse().expect("Position should be valid");
+
+            // Then
+            assert_eq!(parsed.to_string(), board.to_string());
+            assert_eq!(
+                parsed.available_positions(parsed.current_player()),
+                board.available_positions(board.current_player())
+            );
+
+            let moves = board.available_positions(board.current_player());
+            let (x, y) = moves[rng.usize(0..moves.len())];
+            board.place(x, y).expect("Move should be legal");
+        }
+    }
+
+    #[test]
+    fn should_refuse_position_with_wrong_length() {
+        assert_eq!(
+            "XO-- X".parse::<Board>().err(),
+            Some(PositionError::InvalidLength(4))
+        );
+    }
+
+    #[test]
+    fn should_refuse_position_with_unknown_cell() {
+        // Given
+        let position = INITIAL_POSITION.replacen('-', "?", 1);
+
+        // When / Then
+        assert_eq!(
+            position.parse::<Board>().err(),
+            Some(PositionError::InvalidCell {
+                index: 0,
+                found: '?'
+            })
+        );
+    }
+
+    #[test]
+    fn should_refuse_position_without_color_to_move() {
+        assert_eq!(
+            INITIAL_POSITION[..64].parse::<Board>().err(),
+            Some(PositionError::InvalidColorToMove(String::new()))
+        );
+        assert_eq!(
+            format!("{} Z", &INITIAL_POSITION[..64])
+                .parse::<Board>()
+                .err(),
+            Some(PositionError::InvalidColorToMove("Z".to_string()))
+        );
+    }
+}
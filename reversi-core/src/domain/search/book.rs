@@ -0,0 +1,87 @@
+use crate::domain::board::Board;
+use crate::domain::book::OpeningBook;
+use crate::domain::search::{SearchControl, SearchStrategy};
+use std::sync::Mutex;
+
+/// Plays a move of the opening book while the position is in it, then lets another strategy
+/// search.
+pub struct BookStrategy {
+    book: OpeningBook,
+    fallback: Box<dyn SearchStrategy>,
+    rng: Mutex<fastrand::Rng>,
+}
+
+impl BookStrategy {
+    pub fn new(book: OpeningBook, fallback: Box<dyn SearchStrategy>) -> Self {
+        Self {
+            book,
+            fallback,
+            rng: Mutex::new(fastrand::Rng::new()),
+        }
+    }
+
+    fn book_move(&self, board: &Board) -> Option<(usize, usize)> {
+        let mut rng = self
+            .rng
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.book.choose(board, &mut rng)
+    }
+}
+
+impl SearchStrategy for BookStrategy {
+    fn select_move(&self, board: &Board) -> Option<(usize, usize)> {
+        self.book_move(board)
+            .or_else(|| self.fallback.select_move(board))
+    }
+
+    fn select_move_controlled(
+        &self,
+        board: &Board,
+        control: &SearchControl,
+    ) -> Option<(usize, usize)> {
+        self.book_move(board)
+            .or_else(|| self.fallback.select_move_controlled(board, control))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::search::MockSearchStrategy;
+
+    #[test]
+    fn should_play_book_move_without_searching() {
+        // Given
+        let book: OpeningBook = "f5d6".parse().expect("Book should be valid");
+        let mut fallback = MockSearchStrategy::new();
+        fallback.expect_select_move().never();
+        let strategy = BookStrategy::new(book, Box::new(fallback));
+        let board = Board::from_transcript("f5").expect("Transcript should be legal");
+
+        // When
+        let result = strategy.select_move(&board);
+
+        // Then
+        assert_eq!(result, Some((3, 5)));
+    }
+
+    #[test]
+    fn should_search_out_of_book() {
+        // Given
+        let book: OpeningBook = "f5d6".parse().expect("Book should be valid");
+        let mut fallback = MockSearchStrategy::new();
+        fallback
+            .expect_select_move_controlled()
+            .times(1)
+            .return_const(Some((2, 2)));
+        let strategy = BookStrategy::new(book, Box::new(fallback));
+        let board = Board::from_transcript("f5f6").expect("Transcript should be legal");
+
+        // When
+        let result = strategy.select_move_controlled(&board, &SearchControl::default());
+
+        // Then
+        assert_eq!(result, Some((2, 2)));
+    }
+}
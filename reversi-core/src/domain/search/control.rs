@@ -0,0 +1,124 @@
+use crate::domain::search::Budget;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use web_time::Instant;
+
+/// Nodes searched between two looks at the clock.
+const CLOCK_INTERVAL: u64 = 1024;
+
+/// Where a search stands, updated each time it finishes a depth.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct SearchProgress {
+    pub depth: u8,
+    pub nodes: u64,
+    pub best_move: (usize, usize),
+}
+
+/// Shared between a search and whoever waits for it, possibly from another thread, to follow
+/// its progress and cancel it.
+#[derive(Clone, Default)]
+pub struct SearchControl {
+    cancelled: Arc<AtomicBool>,
+    progress: Arc<Mutex<Option<SearchProgress>>>,
+}
+
+impl SearchControl {
+    /// Asks the search to stop as soon as possible.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn report(&self, progress: SearchProgress) {
+        *self
+            .progress
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(progress);
+    }
+
+    /// Last progress reported by the search, if any.
+    pub fn progress(&self) -> Option<SearchProgress> {
+        *self
+            .progress
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Where a search must stop: once cancelled or out of budget, counted from its start.
+pub(super) struct Limits<'a> {
+    pub control: &'a SearchControl,
+    pub budget: Option<Budget>,
+    pub start: Instant,
+    pub nodes: u64,
+    pub aborted: bool,
+}
+
+impl<'a> Limits<'a> {
+    pub fn new(control: &'a SearchControl, budget: Option<Budget>) -> Self {
+        Self {
+            control,
+            budget,
+            start: Instant::now(),
+            nodes: 0,
+            aborted: false,
+        }
+    }
+
+    pub fn exhausted(&self) -> bool {
+        if self.control.is_cancelled() {
+            return true;
+        }
+        match self.budget {
+            Some(Budget::Nodes(nodes)) => self.nodes >= nodes,
+            Some(Budget::Time(time)) => self.start.elapsed() >= time,
+            None => false,
+        }
+    }
+
+    /// Counts a node and tells whether the search must stop. The clock and the cancellation
+    /// are only looked at from time to time.
+    pub fn out_of_budget(&mut self) -> bool {
+        self.nodes += 1;
+        let check = matches!(self.budget, Some(Budget::Nodes(_)))
+            || self.nodes.is_multiple_of(CLOCK_INTERVAL);
+        self.aborted = self.aborted || (check && self.exhausted());
+        self.aborted
+    }
+
+    /// What is left of `budget` once this search is over.
+    pub fn left_of(&self, budget: Budget) -> Budget {
+        match budget {
+            Budget::Nodes(nodes) => Budget::Nodes(nodes.saturating_sub(self.nodes)),
+            Budget::Time(time) => Budget::Time(time.saturating_sub(self.start.elapsed())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_share_cancellation_and_progress_between_clones() {
+        // Given
+        let control = SearchControl::default();
+        let search_side = control.clone();
+        let progress = SearchProgress {
+            depth: 3,
+            nodes: 120,
+            best_move: (5, 4),
+        };
+
+        // When
+        search_side.report(progress);
+        control.cancel();
+
+        // Then
+        assert_eq!(control.progress(), Some(progress));
+        assert!(search_side.is_cancelled());
+    }
+}
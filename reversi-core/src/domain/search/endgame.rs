@@ -0,0 +1,412 @@
+use crate::domain::board::{Board, ColorPiece, Score};
+use crate::domain::search::control::Limits;
+use crate::domain::search::{Bound, Budget, SearchControl, SearchProgress};
+use std::sync::Mutex;
+
+/// Entries of the table of solved positions, a power of two.
+const TABLE_SIZE: usize = 1 << 16;
+
+/// Best move of a solved position, with the outcome of the game when both players play
+/// perfectly.
+#[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug))]
+pub struct Solution {
+    position: (usize, usize),
+    differential: i32,
+    score: Score,
+}
+
+impl Solution {
+    pub fn position(&self) -> (usize, usize) {
+        self.position
+    }
+
+    /// Final pieces of the player to move minus those of the opponent.
+    pub fn differential(&self) -> i32 {
+        self.differential
+    }
+
+    /// Final score, as given by `Board::end_of_game`.
+    pub fn score(&self) -> Score {
+        self.score
+    }
+}
+
+/// Searches to the end of the game once few cells are left empty.
+pub struct EndgameSolver {
+    max_empties: u32,
+    table: Mutex<Vec<Option<Solved>>>,
+}
+
+/// Solved position, or bound of its differential when the search was cut. Since the solver
+/// looks to the end of the game, entries stay true from one move to the next.
+#[derive(Copy, Clone)]
+struct Solved {
+    key: u64,
+    differential: i32,
+    /// Final score of the line which gives the differential.
+    score: Score,
+    bound: Bound,
+    best_move: (usize, usize),
+}
+
+/// State of one solve: the table of solved positions and where to stop.
+struct Solve<'a, 'b> {
+    table: &'a mut [Option<Solved>],
+    limits: &'a mut Limits<'b>,
+}
+
+impl EndgameSolver {
+    pub fn new(max_empties: u32) -> Self {
+        Self {
+            max_empties,
+            table: Mutex::new(vec![None; TABLE_SIZE]),
+        }
+    }
+
+    /// Changes how many empty cells it solves, keeping its table of solved positions.
+    pub fn set_max_empties(&mut self, max_empties: u32) {
+        self.max_empties = max_empties;
+    }
+
+    /// Whether the position is in play with at most `max_empties` empty cells.
+    pub fn can_solve(&self, board: &Board) -> bool {
+        board.empties() <= self.max_empties && board.end_of_game().is_none()
+    }
+
+    /// Solves the position when it has at most `max_empties` empty cells. `None` when there
+    /// are more, or when the player to move has no move.
+    pub fn solve(&self, board: &Board) -> Option<Solution> {
+        self.solve_controlled(board, &SearchControl::default(), None)
+    }
+
+    /// Same as `solve`, reporting to `control` once solved. `None` as well when cancelled or
+    /// when the budget runs out first.
+    pub fn solve_controlled(
+        &self,
+        board: &Board,
+        control: &SearchControl,
+        budget: Option<Budget>,
+    ) -> Option<Solution> {
+        self.solve_within(board, &mut Limits::new(control, budget))
+    }
+
+    pub(super) fn solve_within(&self, board: &Board, limits: &mut Limits) -> Option<Solution> {
+        if !self.can_solve(board) {
+            return None;
+        }
+
+        let mut table = self
+            .table
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut solve = Solve {
+            table: &mut table,
+            limits,
+        };
+        let mut board = board.clone();
+        let player = board.current_player().color();
+        let best_move = solve.probe(board.hash()).map(|entry| entry.best_move);
+        let mut alpha = -i32::MAX;
+        let mut best: Option<Solution> = None;
+        for (x, y) in ordered_moves(&mut board, best_move) {
+            board.place(x, y).expect("Available move should be legal");
+            let result = if board.current_player().color() == player {
+                solve.solve(&mut board, alpha, i32::MAX)
+            } else {
+                solve
+                    .solve(&mut board, -i32::MAX, -alpha)
+                    .map(|(differential, score)| (-differential, score))
+            };
+            board.undo();
+            let (differential, score) = result?;
+            if best.is_none() || differential > alpha {
+                alpha = differential;
+                best = Some(Solution {
+                    position: (x, y),
+                    differential,
+                    score,
+                });
+            }
+        }
+
+        let solution = best?;
+        solve.limits.control.report(SearchProgress {
+            depth: board.empties() as u8,
+            nodes: solve.limits.nodes,
+            best_move: solution.position,
+        });
+        solve.store(Solved {
+            key: board.hash(),
+            differential: solution.differential,
+            score: solution.score,
+            bound: Bound::Exact,
+            best_move: solution.position,
+        });
+        Some(solution)
+    }
+}
+
+impl Solve<'_, '_> {
+    fn index(&self, key: u64) -> usize {
+        key as usize & (self.table.len() - 1)
+    }
+
+    fn probe(&self, key: u64) -> Option<Solved> {
+        self.table[self.index(key)].filter(|entry| entry.key == key)
+    }
+
+    fn store(&mut self, entry: Solved) {
+        let index = self.index(entry.key);
+        self.table[index] = Some(entry);
+    }
+
+    /// Final differential for the player to move, with the final score of the line which
+    /// gives it. `None` once the search must stop.
+    fn solve(&mut self, board: &mut Board, mut alpha: i32, mut beta: i32) -> Option<(i32, Score)> {
+        if self.limits.out_of_budget() {
+            return None;
+        }
+        if let Some(score) = board.end_of_game() {
+            return Some((differential(&score, board.current_player().color()), score));
+        }
+
+        let entry = self.probe(board.hash());
+        if let Some(entry) = entry {
+            let result = Some((entry.differential, entry.score));
+            match entry.bound {
+                Bound::Exact => return result,
+                Bound::Lower => alpha = alpha.max(entry.differential),
+                Bound::Upper => beta = beta.min(entry.differential),
+            }
+            if alpha >= beta {
+                return result;
+            }
+        }
+
+        let original_alpha = alpha;
+        let player = board.current_player().color();
+        let mut best: Option<((usize, usize), i32, Score)> = None;
+        for (x, y) in ordered_moves(board, entry.map(|entry| entry.best_move)) {
+            board.place(x, y).expect("Available move should be legal");
+            let result = if board.current_player().color() == player {
+                self.solve(board, alpha, beta)
+            } else {
+                self.solve(board, -beta, -alpha)
+                    .map(|(differential, score)| (-differential, score))
+            };
+            board.undo();
+            let (differential, score) = result?;
+
+            if best.is_none_or(|(_, best, _)| differential > best) {
+                best = Some(((x, y), differential, score));
+            }
+            alpha = alpha.max(differential);
+            if alpha >= beta {
+                break;
+            }
+        }
+        // The board plays passes on its own, so a game in play always has a move
+        let (best_move, differential, score) = best.expect("Position in play should have a move");
+
+        let bound = if differential <= original_alpha {
+            Bound::Upper
+        } else if differential >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        self.store(Solved {
+            key: board.hash(),
+            differential,
+            score,
+            bound,
+            best_move,
+        });
+        Some((differential, score))
+    }
+}
+
+fn differential(score: &Score, color: ColorPiece) -> i32 {
+    let black = score.player1() as i32 - score.player2() as i32;
+    match color {
+        ColorPiece::Black => black,
+        ColorPiece::White => -black,
+    }
+}
+
+/// Best move found by a previous solve first, then the moves leaving the fewest replies to the
+/// opponent, as they are the most likely to cut.
+fn ordered_moves(board: &mut Board, best_move: Option<(usize, usize)>) -> Vec<(usize, usize)> {
+    let player = board.current_player().color();
+    let mut moves: Vec<((usize, usize), u32)> = board
+        .available_positions(board.current_player())
+        .into_iter()
+        .map(|(x, y)| {
+            board.place(x, y).expect("Available move should be legal");
+            let opponent = board.current_player().color();
+            let replies = if opponent == player {
+                0
+            } else {
+                board.legal_moves(opponent).count_ones()
+            };
+            board.undo();
+            ((x, y), replies)
+        })
+        .collect();
+    moves.sort_by_key(|(position, replies)| (Some(*position) != best_move, *replies));
+    moves.into_iter().map(|(position, _)| position).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every line played to the end, to check the solver.
+    fn exhaustive(board: &mut Board) -> i32 {
+        if let Some(score) = board.end_of_game() {
+            return differential(&score, board.current_player().color());
+        }
+        let player = board.current_player().color();
+        let mut best = -i32::MAX;
+        for (x, y) in board.available_positions(board.current_player()) {
+            board.place(x, y).expect("Available move should be legal");
+            let differential = if board.current_player().color() == player {
+                exhaustive(board)
+            } else {
+                -exhaustive(board)
+            };
+            board.undo();
+            best = best.max(differential);
+        }
+        best
+    }
+
+    fn random_endgame(seed: u64, empties: u32) -> Board {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let mut board = Board::default();
+        while board.empties() > empties && board.end_of_game().is_none() {
+            let moves = board.available_positions(board.current_player());
+            let (x, y) = moves[rng.usize(0..moves.len())];
+            board.place(x, y).expect("Move should be legal");
+        }
+        board
+    }
+
+    #[test]
+    fn should_find_same_differential_as_exhaustive_search() {
+        for seed in 0..10 {
+            // Given
+            let board = random_endgame(seed, 7);
+            if board.end_of_game().is_some() {
+                continue;
+            }
+
+            // When
+            let result = EndgameSolver::new(7).solve(&board);
+
+            // Then
+            let solution = result.expect("Position should be solved");
+            assert_eq!(solution.differential(), exhaustive(&mut board.clone()));
+        }
+    }
+
+    #[test]
+    fn should_give_final_score_matching_differential() {
+        // Given
+        let board = random_endgame(42, 8);
+        let player = board.current_player().color();
+
+        // When
+        let result = EndgameSolver::new(10).solve(&board);
+
+        // Then
+        let solution = result.expect("Position should be solved");
+        assert_eq!(
+            differential(&solution.score(), player),
+            solution.differential()
+        );
+        assert!(
+            board
+                .available_positions(board.current_player())
+                .contains(&solution.position())
+        );
+    }
+
+    #[test]
+    fn should_play_last_empty_cell() {
+        // Given
+        let board = random_endgame(5, 1);
+        let mut played = board.clone();
+        let (x, y) = played.available_positions(played.current_player())[0];
+        played.place(x, y).expect("Move should be legal");
+        let score = played.end_of_game().expect("Game should be over");
+
+        // When
+        let result = EndgameSolver::new(1).solve(&board);
+
+        // Then
+        let solution = result.expect("Position should be solved");
+        assert_eq!(solution.position(), (x, y));
+        assert_eq!(
+            solution.differential(),
+            differential(&score, board.current_player().color())
+        );
+    }
+
+    #[test]
+    fn should_not_solve_positions_with_too_many_empty_cells() {
+        // Given
+        let board = Board::default();
+
+        // When / Then
+        assert!(EndgameSolver::new(20).solve(&board).is_none());
+    }
+
+    #[test]
+    fn should_give_up_when_cancelled_or_out_of_budget() {
+        // Given
+        let board = random_endgame(7, 12);
+        let solver = EndgameSolver::new(12);
+        let cancelled = SearchControl::default();
+        cancelled.cancel();
+
+        // When
+        let without_nodes =
+            solver.solve_controlled(&board, &SearchControl::default(), Some(Budget::Nodes(10)));
+        let without_control = solver.solve_controlled(&board, &cancelled, None);
+
+        // Then
+        assert!(without_nodes.is_none());
+        assert!(without_control.is_none());
+        assert!(solver.solve(&board).is_some());
+    }
+
+    #[test]
+    fn should_solve_again_from_its_table() {
+        // Given
+        let board = random_endgame(11, 10);
+        let solver = EndgameSolver::new(10);
+        let first = SearchControl::default();
+        let second = SearchControl::default();
+
+        // When
+        let solution = solver.solve_controlled(&board, &first, None);
+        let again = solver.solve_controlled(&board, &second, None);
+
+        // Then
+        let (solution, again) = (
+            solution.expect("Position should be solved"),
+            again.expect("Position should be solved"),
+        );
+        assert_eq!(again.differential(), solution.differential());
+        let nodes = |control: &SearchControl| {
+            control
+                .progress()
+                .expect("Progress should be reported")
+                .nodes
+        };
+        assert!(nodes(&second) < nodes(&first));
+        assert_eq!(first.progress().map(|progress| progress.depth), Some(10));
+    }
+}
@@ -0,0 +1,258 @@
+use crate::domain::board::{Board, ColorPiece};
+use crate::domain::search::{SearchControl, SearchProgress, SearchStrategy};
+
+/// Exploration constant of UCT, `√2` in theory for results between 0 and 1.
+pub const DEFAULT_EXPLORATION: f64 = std::f64::consts::SQRT_2;
+/// Iterations between two progress reports, and two looks at the cancellation.
+const REPORT_INTERVAL: u32 = 256;
+const CORNERS: [(usize, usize); 4] = [(0, 0), (7, 0), (0, 7), (7, 7)];
+
+/// Monte Carlo tree search: plays `iterations` games to the end, choosing their first moves
+/// by UCT and the others at random (taking a corner when one is available), and plays the
+/// move which was tried the most.
+pub struct MctsStrategy {
+    iterations: u32,
+    exploration: f64,
+    seed: Option<u64>,
+}
+
+/// Position of the tree, reached by playing `position` from its parent.
+struct Node {
+    position: (usize, usize),
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried: Vec<(usize, usize)>,
+    /// Color which played `position`, the results are counted for it.
+    player: Option<ColorPiece>,
+    visits: u32,
+    /// 1 per game won, 0.5 per draw.
+    wins: f64,
+}
+
+impl Node {
+    fn new(
+        position: (usize, usize),
+        parent: Option<usize>,
+        player: Option<ColorPiece>,
+        board: &Board,
+    ) -> Self {
+        Self {
+            position,
+            parent,
+            children: Vec::new(),
+            untried: board.available_positions(board.current_player()),
+            player,
+            visits: 0,
+            wins: 0.0,
+        }
+    }
+}
+
+impl MctsStrategy {
+    pub fn new(iterations: u32) -> Self {
+        Self {
+            iterations: iterations.max(1),
+            exploration: DEFAULT_EXPLORATION,
+            seed: None,
+        }
+    }
+
+    /// Higher values try the less promising moves more often.
+    pub fn with_exploration(mut self, exploration: f64) -> Self {
+        self.exploration = exploration;
+        self
+    }
+
+    /// Plays the same random games, and so the same move, from one search to the other.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Child of `node` with the best upper confidence bound.
+    fn select_child(&self, tree: &[Node], node: usize) -> usize {
+        let log_visits = f64::from(tree[node].visits).ln();
+        let uct = |child: usize| {
+            let child = &tree[child];
+            let visits = f64::from(child.visits);
+            child.wins / visits + self.exploration * (log_visits / visits).sqrt()
+        };
+        tree[node]
+            .children
+            .iter()
+            .copied()
+            .max_by(|&a, &b| uct(a).total_cmp(&uct(b)))
+            .expect("Node should have children")
+    }
+}
+
+/// Plays the game to the end and gives the winner, `None` for a draw.
+fn playout(board: &mut Board, rng: &mut fastrand::Rng) -> Option<ColorPiece> {
+    loop {
+        if let Some(score) = board.end_of_game() {
+            return score.winner();
+        }
+        let moves = board.available_positions(board.current_player());
+        let (x, y) = moves
+            .iter()
+            .copied()
+            .find(|position| CORNERS.contains(position))
+            .unwrap_or_else(|| moves[rng.usize(0..moves.len())]);
+        board.place(x, y).expect("Move should be legal");
+    }
+}
+
+impl SearchStrategy for MctsStrategy {
+    fn select_move(&self, board: &Board) -> Option<(usize, usize)> {
+        self.select_move_controlled(board, &SearchControl::default())
+    }
+
+    fn select_move_controlled(
+        &self,
+        board: &Board,
+        control: &SearchControl,
+    ) -> Option<(usize, usize)> {
+        let root = Node::new((0, 0), None, None, board);
+        match root.untried.len() {
+            0 => return None,
+            1 => return root.untried.first().copied(),
+            _ => {}
+        }
+        let mut rng = match self.seed {
+            Some(seed) => fastrand::Rng::with_seed(seed),
+            None => fastrand::Rng::new(),
+        };
+        let mut tree = vec![root];
+        let most_visited = |tree: &[Node]| {
+            tree[0]
+                .children
+                .iter()
+                .map(|&child| &tree[child])
+                .max_by_key(|child| child.visits)
+                .map(|child| child.position)
+        };
+
+        for iteration in 1..=self.iterations {
+            let mut board = board.clone();
+            let mut node = 0;
+            let mut depth = 0;
+
+            // Selection
+            while tree[node].untried.is_empty() && !tree[node].children.is_empty() {
+                node = self.select_child(&tree, node);
+                let (x, y) = tree[node].position;
+                board.place(x, y).expect("Move should be legal");
+                depth += 1;
+            }
+
+            // Expansion
+            if !tree[node].untried.is_empty() {
+                let index = rng.usize(0..tree[node].untried.len());
+                let (x, y) = tree[node].untried.swap_remove(index);
+                let player = board.current_player().color();
+                board.place(x, y).expect("Move should be legal");
+                tree.push(Node::new((x, y), Some(node), Some(player), &board));
+                let child = tree.len() - 1;
+                tree[node].children.push(child);
+                node = child;
+                depth += 1;
+            }
+
+            // Simulation and backpropagation
+            let winner = playout(&mut board, &mut rng);
+            let mut current = Some(node);
+            while let Some(index) = current {
+                let node = &mut tree[index];
+                node.visits += 1;
+                node.wins += match (winner, node.player) {
+                    (None, _) => 0.5,
+                    (Some(winner), Some(player)) if winner == player => 1.0,
+                    _ => 0.0,
+                };
+                current = node.parent;
+            }
+
+            if iteration.is_multiple_of(REPORT_INTERVAL) || iteration == self.iterations {
+                if let Some(best_move) = most_visited(&tree) {
+                    control.report(SearchProgress {
+                        depth,
+                        nodes: u64::from(iteration),
+                        best_move,
+                    });
+                }
+                if control.is_cancelled() {
+                    break;
+                }
+            }
+        }
+        most_visited(&tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_take_corner() {
+        // Given
+        let board: Board = format!("{}XO{}XO- X", "-".repeat(24), "-".repeat(35))
+            .parse()
+            .expect("Position should be valid");
+        let strategy = MctsStrategy::new(2000).with_seed(3);
+
+        // When
+        let result = strategy.select_move(&board);
+
+        // Then
+        assert_eq!(result, Some((7, 7)));
+    }
+
+    #[test]
+    fn should_play_same_move_with_same_seed() {
+        // Given
+        let board = Board::from_transcript("f5d6c3d3c4").expect("Transcript should be legal");
+        let strategy = MctsStrategy::new(500).with_exploration(0.5).with_seed(42);
+
+        // When
+        let first = strategy.select_move(&board);
+        let second = strategy.select_move(&board);
+
+        // Then
+        assert_eq!(first, second);
+        let position = first.expect("A move should be found");
+        assert!(
+            board
+                .available_positions(board.current_player())
+                .contains(&position)
+        );
+    }
+
+    #[test]
+    fn should_stop_when_cancelled() {
+        // Given
+        let board = Board::default();
+        let strategy = MctsStrategy::new(u32::MAX).with_seed(1);
+        let control = SearchControl::default();
+        control.cancel();
+
+        // When
+        let result = strategy.select_move_controlled(&board, &control);
+
+        // Then
+        let progress = control.progress().expect("Progress should be reported");
+        assert_eq!(progress.nodes, u64::from(REPORT_INTERVAL));
+        assert_eq!(Some(progress.best_move), result);
+    }
+
+    #[test]
+    fn should_return_none_when_no_move_is_available() {
+        // Given
+        let board: Board = format!("{} X", "O".repeat(64))
+            .parse()
+            .expect("Position should be valid");
+
+        // When / Then
+        assert_eq!(MctsStrategy::new(100).select_move(&board), None);
+    }
+}
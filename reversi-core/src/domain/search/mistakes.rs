@@ -0,0 +1,75 @@
+use crate::domain::board::Board;
+use crate::domain::search::{RandomStrategy, SearchControl, SearchStrategy};
+
+/// Plays a random move instead of the one chosen by `strategy` with probability `rate`, to
+/// give weaker opponents.
+pub struct MistakeStrategy {
+    strategy: Box<dyn SearchStrategy>,
+    rate: f32,
+}
+
+impl MistakeStrategy {
+    pub fn new(strategy: Box<dyn SearchStrategy>, rate: f32) -> Self {
+        Self { strategy, rate }
+    }
+}
+
+impl SearchStrategy for MistakeStrategy {
+    fn select_move(&self, board: &Board) -> Option<(usize, usize)> {
+        self.select_move_controlled(board, &SearchControl::default())
+    }
+
+    fn select_move_controlled(
+        &self,
+        board: &Board,
+        control: &SearchControl,
+    ) -> Option<(usize, usize)> {
+        if fastrand::f32() < self.rate {
+            RandomStrategy {}.select_move(board)
+        } else {
+            self.strategy.select_move_controlled(board, control)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::search::MockSearchStrategy;
+
+    #[test]
+    fn should_follow_strategy_without_mistakes() {
+        // Given
+        let mut strategy = MockSearchStrategy::new();
+        strategy
+            .expect_select_move_controlled()
+            .times(20)
+            .return_const(Some((5, 4)));
+        let mistakes = MistakeStrategy::new(Box::new(strategy), 0.0);
+
+        // When / Then
+        for _ in 0..20 {
+            assert_eq!(mistakes.select_move(&Board::default()), Some((5, 4)));
+        }
+    }
+
+    #[test]
+    fn should_play_random_legal_moves_when_always_mistaken() {
+        // Given
+        let mut strategy = MockSearchStrategy::new();
+        strategy.expect_select_move_controlled().never();
+        let mistakes = MistakeStrategy::new(Box::new(strategy), 1.0);
+        let board = Board::default();
+
+        // When
+        let result = mistakes.select_move(&board);
+
+        // Then
+        let position = result.expect("A move should be found");
+        assert!(
+            board
+                .available_positions(board.current_player())
+                .contains(&position)
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Strategies used by the AI to choose its move.
+
+mod book;
+mod control;
+mod endgame;
+mod mcts;
+mod mistakes;
+mod negamax;
+mod random;
+mod transposition;
+
+pub use book::BookStrategy;
+pub use control::{SearchControl, SearchProgress};
+pub use endgame::{EndgameSolver, Solution};
+pub use mcts::{DEFAULT_EXPLORATION, MctsStrategy};
+pub use mistakes::MistakeStrategy;
+pub use negamax::{Analysis, Deepening, NegamaxStrategy};
+pub use random::RandomStrategy;
+pub use transposition::{Bound, Entry, TranspositionTable};
+
+use crate::domain::board::Board;
+use std::time::Duration;
+
+#[cfg(test)]
+use mockall::automock;
+
+/// How long a search may go on before playing the best move found so far.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Budget {
+    /// Wall-clock time.
+    Time(Duration),
+    /// Number of positions visited, which gives the same move from one run to the other.
+    Nodes(u64),
+}
+
+#[cfg_attr(test, automock)]
+pub trait SearchStrategy: Send + Sync {
+    /// Position to play for the current player, `None` when they have no move.
+    fn select_move(&self, board: &Board) -> Option<(usize, usize)>;
+
+    /// Same as `select_move`, reporting progress to `control`. Once cancelled, the search
+    /// stops early and its move should not be played.
+    fn select_move_controlled(
+        &self,
+        board: &Board,
+        _control: &SearchControl,
+    ) -> Option<(usize, usize)> {
+        self.select_move(board)
+    }
+}
@@ -0,0 +1,681 @@
+use crate::domain::board::Board;
+use crate::domain::evaluation::{Evaluator, PositionalEvaluator};
+use crate::domain::search::control::Limits;
+use crate::domain::search::{
+    Bound, Budget, EndgameSolver, Entry, SearchControl, SearchProgress, SearchStrategy,
+    TranspositionTable,
+};
+use std::sync::Mutex;
+
+/// Any finished game is worth more than a position still in play.
+const WIN: i32 = 100_000;
+const INFINITY: i32 = i32::MAX;
+/// Entries of the transposition table, kept from one move to the next.
+const TABLE_SIZE: usize = 1 << 16;
+
+/// Negamax search with alpha-beta pruning, looking `depth` moves ahead. With a budget, it
+/// deepens one move at a time up to `depth` and plays the best move of the deepest search
+/// finished within the budget.
+pub struct NegamaxStrategy {
+    depth: u8,
+    budget: Option<Budget>,
+    evaluator: Box<dyn Evaluator>,
+    endgame: Option<EndgameSolver>,
+    table: Mutex<TranspositionTable>,
+}
+
+/// Best move found by a search, with its score for the player to move.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Analysis {
+    position: (usize, usize),
+    score: i32,
+    depth: u8,
+}
+
+impl Analysis {
+    pub fn position(&self) -> (usize, usize) {
+        self.position
+    }
+
+    /// In evaluator units, or beyond `±100_000` once the end of the game is in sight, the
+    /// disc difference being added to it.
+    pub fn score(&self) -> i32 {
+        self.score
+    }
+
+    /// Depth of the deepest search finished.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+}
+
+/// Iterative deepening spread over several calls to `NegamaxStrategy::deepen`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Deepening {
+    best: Option<((usize, usize), i32, u8)>,
+    finished: bool,
+}
+
+impl Deepening {
+    /// Best move of the deepest search finished so far.
+    pub fn best_move(&self) -> Option<(usize, usize)> {
+        self.best.map(|(position, _, _)| position)
+    }
+
+    /// Whether going on cannot change the move: the full depth is reached or the end solved.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+/// State of one search: where to stop and how far it went.
+struct Search<'a> {
+    table: &'a mut TranspositionTable,
+    limits: Limits<'a>,
+}
+
+impl NegamaxStrategy {
+    pub fn new(depth: u8) -> Self {
+        Self::with_evaluator(depth, Box::new(PositionalEvaluator::default()))
+    }
+
+    pub fn with_evaluator(depth: u8, evaluator: Box<dyn Evaluator>) -> Self {
+        Self {
+            depth: depth.max(1),
+            budget: None,
+            evaluator,
+            endgame: None,
+            table: Mutex::new(TranspositionTable::new(TABLE_SIZE)),
+        }
+    }
+
+    /// Searches by iterative deepening until the budget runs out.
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Plays the perfect move given by the solver once it can solve the position.
+    pub fn with_endgame_solver(mut self, solver: EndgameSolver) -> Self {
+        self.endgame = Some(solver);
+        self
+    }
+
+    /// Changes the maximum depth, keeping the table of the previous searches.
+    pub fn set_depth(&mut self, depth: u8) {
+        self.depth = depth.max(1);
+    }
+
+    /// Best move for the current player with its score, without the endgame solver.
+    pub fn analyze(&self, board: &Board, control: &SearchControl) -> Option<Analysis> {
+        self.analyze_within(board, control, self.budget)
+    }
+
+    /// Same as `analyze`, within `budget` instead of the budget of the strategy.
+    pub fn analyze_within(
+        &self,
+        board: &Board,
+        control: &SearchControl,
+        budget: Option<Budget>,
+    ) -> Option<Analysis> {
+        self.search(board, control, budget)
+            .map(|(position, score, depth)| Analysis {
+                position,
+                score,
+                depth,
+            })
+    }
+
+    /// Goes on with `deepening` within the budget, from the deepest search it finished, for the
+    /// frontends which spread a search over several calls. The tables keep what the searches cut
+    /// short had found, so that each call starts about where the previous one stopped.
+    pub fn deepen(&self, board: &Board, deepening: &mut Deepening, control: &SearchControl) {
+        if deepening.finished {
+            return;
+        }
+        let mut budget = self.budget;
+        if let Some(solver) = self
+            .endgame
+            .as_ref()
+            .filter(|solver| solver.can_solve(board))
+        {
+            let mut limits = Limits::new(control, budget.map(halve));
+            if let Some(solution) = solver.solve_within(board, &mut limits) {
+                let score = final_score(solution.differential());
+                deepening.best = Some((solution.position(), score, board.empties() as u8));
+                deepening.finished = true;
+                return;
+            }
+            budget = budget.map(|budget| limits.left_of(budget));
+        }
+        self.deepen_within(board, control, budget, deepening);
+    }
+
+    /// Best move for the current player with its score, from their point of view, along with
+    /// the depth of the search it comes from.
+    fn search(
+        &self,
+        board: &Board,
+        control: &SearchControl,
+        budget: Option<Budget>,
+    ) -> Option<((usize, usize), i32, u8)> {
+        let mut deepening = Deepening::default();
+        self.deepen_within(board, control, budget, &mut deepening);
+        deepening.best
+    }
+
+    /// Deepens one move at a time from the deepest search `deepening` finished, until the
+    /// budget runs out or the full depth is reached. Without a budget, searches at full depth at
+    /// once.
+    fn deepen_within(
+        &self,
+        board: &Board,
+        control: &SearchControl,
+        budget: Option<Budget>,
+        deepening: &mut Deepening,
+    ) {
+        let mut table = self
+            .table
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if deepening.best.is_none() {
+            table.new_search();
+        }
+        let mut board = board.clone();
+        let mut search = Search {
+            table: &mut table,
+            limits: Limits::new(control, None),
+        };
+        if budget.is_none() {
+            deepening.best = self
+                .search_root(&mut board, &mut search, self.depth)
+                .map(|(position, score)| (position, score, self.depth));
+            deepening.finished = true;
+            return;
+        }
+
+        // The first search is always finished, so that there is a move to play
+        if deepening.best.is_none() {
+            deepening.best = self
+                .search_root(&mut board, &mut search, 1)
+                .map(|(position, score)| (position, score, 1));
+        }
+        search.limits.budget = budget;
+        loop {
+            let Some((_, _, depth)) = deepening.best.filter(|(_, _, depth)| *depth < self.depth)
+            else {
+                deepening.finished = true;
+                return;
+            };
+            if search.limits.exhausted() {
+                return;
+            }
+            match self.search_root(&mut board, &mut search, depth + 1) {
+                Some((position, score)) if !search.limits.aborted => {
+                    deepening.best = Some((position, score, depth + 1));
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn search_root(
+        &self,
+        board: &mut Board,
+        search: &mut Search,
+        depth: u8,
+    ) -> Option<((usize, usize), i32)> {
+        let player = board.current_player().color();
+        let best_move = search
+            .table
+            .probe(board.hash())
+            .and_then(|entry| entry.best_move);
+        let mut alpha = -INFINITY;
+        let mut best = None;
+        for (x, y) in ordered_moves(board, best_move) {
+            board.place(x, y).expect("Available move should be legal");
+            let score = if board.current_player().color() == player {
+                self.negamax(board, search, depth - 1, alpha, INFINITY)
+            } else {
+                -self.negamax(board, search, depth - 1, -INFINITY, -alpha)
+            };
+            board.undo();
+            if search.limits.aborted {
+                return best;
+            }
+            if best.is_none() || score > alpha {
+                alpha = score;
+                best = Some(((x, y), score));
+            }
+        }
+
+        if let Some((position, score)) = best {
+            search.limits.control.report(SearchProgress {
+                depth,
+                nodes: search.limits.nodes,
+                best_move: position,
+            });
+            search.table.store(Entry {
+                key: board.hash(),
+                depth,
+                score,
+                bound: Bound::Exact,
+                best_move: Some(position),
+            });
+        }
+        best
+    }
+
+    /// Score of the position for the player to move. The board plays passes on its own, so
+    /// the same player may move again after a move. Once the budget is exhausted, the score is
+    /// meaningless and nothing more is stored in the table.
+    fn negamax(
+        &self,
+        board: &mut Board,
+        search: &mut Search,
+        depth: u8,
+        mut alpha: i32,
+        mut beta: i32,
+    ) -> i32 {
+        if search.limits.out_of_budget() {
+            return 0;
+        }
+        if depth == 0 || board.end_of_game().is_some() {
+            return self.evaluate(board);
+        }
+
+        let entry = search.table.probe(board.hash()).copied();
+        if let Some(entry) = entry
+            && entry.depth >= depth
+        {
+            match entry.bound {
+                Bound::Exact => return entry.score,
+                Bound::Lower => alpha = alpha.max(entry.score),
+                Bound::Upper => beta = beta.min(entry.score),
+            }
+            if alpha >= beta {
+                return entry.score;
+            }
+        }
+
+        let original_alpha = alpha;
+        let player = board.current_player().color();
+        let mut best = -INFINITY;
+        let mut best_move = None;
+        for (x, y) in ordered_moves(board, entry.and_then(|entry| entry.best_move)) {
+            board.place(x, y).expect("Available move should be legal");
+            let score = if board.current_player().color() == player {
+                self.negamax(board, search, depth - 1, alpha, beta)
+            } else {
+                -self.negamax(board, search, depth - 1, -beta, -alpha)
+            };
+            board.undo();
+            if search.limits.aborted {
+                return 0;
+            }
+
+            if score > best {
+                best = score;
+                best_move = Some((x, y));
+            }
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        let bound = if best <= original_alpha {
+            Bound::Upper
+        } else if best >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        search.table.store(Entry {
+            key: board.hash(),
+            depth,
+            score: best,
+            bound,
+            best_move,
+        });
+        best
+    }
+
+    /// Finished games are scored on the final disc difference, the others by the evaluator.
+    fn evaluate(&self, board: &Board) -> i32 {
+        let player = board.current_player();
+        if board.end_of_game().is_some() {
+            let difference = board.pieces(player.color()).count_ones() as i32
+                - board.pieces(player.opponent_color()).count_ones() as i32;
+            return final_score(difference);
+        }
+        self.evaluator.evaluate(board, player.color())
+    }
+}
+
+/// Score of a finished game with this disc difference, beyond any position still in play.
+fn final_score(difference: i32) -> i32 {
+    difference.signum() * WIN + difference
+}
+
+/// Legal moves, starting with the best one found by a previous search.
+fn ordered_moves(board: &Board, best_move: Option<(usize, usize)>) -> Vec<(usize, usize)> {
+    let mut moves = board.available_positions(board.current_player());
+    if let Some(index) = best_move.and_then(|best| moves.iter().position(|mv| *mv == best)) {
+        moves[..=index].rotate_right(1);
+    }
+    moves
+}
+
+fn halve(budget: Budget) -> Budget {
+    match budget {
+        Budget::Nodes(nodes) => Budget::Nodes(nodes / 2),
+        Budget::Time(time) => Budget::Time(time / 2),
+    }
+}
+
+impl SearchStrategy for NegamaxStrategy {
+    fn select_move(&self, board: &Board) -> Option<(usize, usize)> {
+        self.select_move_controlled(board, &SearchControl::default())
+    }
+
+    fn select_move_controlled(
+        &self,
+        board: &Board,
+        control: &SearchControl,
+    ) -> Option<(usize, usize)> {
+        let Some(solver) = self
+            .endgame
+            .as_ref()
+            .filter(|solver| solver.can_solve(board))
+        else {
+            return self
+                .search(board, control, self.budget)
+                .map(|(position, _, _)| position);
+        };
+        // The solver gets half of the budget, the search what it leaves when it cannot finish
+        let mut limits = Limits::new(control, self.budget.map(halve));
+        if let Some(solution) = solver.solve_within(board, &mut limits) {
+            return Some(solution.position());
+        }
+        let budget = self.budget.map(|budget| limits.left_of(budget));
+        self.search(board, control, budget)
+            .map(|(position, _, _)| position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::board::Case;
+    use crate::domain::board::ColorPiece::Black;
+    use crate::domain::evaluation::MockEvaluator;
+    use std::time::Duration;
+
+    /// Plain minimax without pruning, to check the values found by the search.
+    fn minimax(strategy: &NegamaxStrategy, board: &mut Board, depth: u8) -> i32 {
+        if depth == 0 || board.end_of_game().is_some() {
+            return strategy.evaluate(board);
+        }
+        let player = board.current_player().color();
+        let mut best = -INFINITY;
+        for (x, y) in board.available_positions(board.current_player()) {
+            board.place(x, y).expect("Available move should be legal");
+            let score = if board.current_player().color() == player {
+                minimax(strategy, board, depth - 1)
+            } else {
+                -minimax(strategy, board, depth - 1)
+            };
+            board.undo();
+            best = best.max(score);
+        }
+        best
+    }
+
+    #[test]
+    fn should_find_same_score_as_minimax() {
+        let mut rng = fastrand::Rng::with_seed(3);
+        let strategy = NegamaxStrategy::new(3);
+        let mut board = Board::default();
+        while board.end_of_game().is_none() {
+            // When
+            let result = strategy.search(&board, &SearchControl::default(), strategy.budget);
+
+            // Then
+            let (_, score, _) = result.expect("A move should be found");
+            assert_eq!(score, minimax(&strategy, &mut board.clone(), 3));
+
+            let moves = board.available_positions(board.current_player());
+            let (x, y) = moves[rng.usize(0..moves.len())];
+            board.place(x, y).expect("Move should be legal");
+        }
+    }
+
+    #[test]
+    fn should_take_corner() {
+        // Given
+        let board: Board = format!("{}XO{}XO- X", "-".repeat(24), "-".repeat(35))
+            .parse()
+            .expect("Position should be valid");
+        let strategy = NegamaxStrategy::new(1);
+
+        // When
+        let result = strategy.select_move(&board);
+
+        // Then
+        assert_eq!(result, Some((7, 7)));
+    }
+
+    #[test]
+    fn should_choose_move_preferred_by_evaluator() {
+        // Given
+        let mut evaluator = MockEvaluator::new();
+        evaluator.expect_evaluate().returning(|board, _| {
+            // Scored for white, who has to move after black
+            if board.cell(4, 5) == Some(&Case::Piece(Black)) {
+                -100
+            } else {
+                0
+            }
+        });
+        let strategy = NegamaxStrategy::with_evaluator(1, Box::new(evaluator));
+
+        // When
+        let result = strategy.select_move(&Board::default());
+
+        // Then
+        assert_eq!(result, Some((4, 5)));
+    }
+
+    #[test]
+    fn should_reach_maximum_depth_within_a_large_budget() {
+        // Given
+        let board = Board::from_transcript("f5d6c3d3c4").expect("Transcript should be legal");
+        let deepening = NegamaxStrategy::new(4).with_budget(Budget::Nodes(u64::MAX));
+
+        // When
+        let result = deepening.search(&board, &SearchControl::default(), deepening.budget);
+
+        // Then
+        let (_, score, depth) = result.expect("A move should be found");
+        assert_eq!(depth, 4);
+        let (_, fixed_score, _) = NegamaxStrategy::new(4)
+            .search(&board, &SearchControl::default(), None)
+            .unwrap();
+        assert_eq!(score, fixed_score);
+    }
+
+    #[test]
+    fn should_stop_deepening_when_budget_is_exhausted() {
+        // Given
+        let board = Board::from_transcript("f5d6c3d3c4").expect("Transcript should be legal");
+        let strategy = NegamaxStrategy::new(10).with_budget(Budget::Nodes(500));
+
+        // When
+        let result = strategy.search(&board, &SearchControl::default(), strategy.budget);
+
+        // Then
+        let (position, _, depth) = result.expect("A move should be found");
+        assert!(depth < 10);
+        assert!(
+            board
+                .available_positions(board.current_player())
+                .contains(&position)
+        );
+    }
+
+    #[test]
+    fn should_play_a_move_even_without_time() {
+        // Given
+        let board = Board::default();
+        let strategy = NegamaxStrategy::new(60).with_budget(Budget::Time(Duration::ZERO));
+
+        // When
+        let result = strategy.search(&board, &SearchControl::default(), strategy.budget);
+
+        // Then
+        let (_, _, depth) = result.expect("A move should be found");
+        assert_eq!(depth, 1);
+    }
+
+    #[test]
+    fn should_analyze_within_given_budget() {
+        // Given
+        let board = Board::default();
+        let strategy = NegamaxStrategy::new(60).with_budget(Budget::Time(Duration::MAX));
+
+        // When
+        let result = strategy.analyze_within(
+            &board,
+            &SearchControl::default(),
+            Some(Budget::Time(Duration::ZERO)),
+        );
+
+        // Then
+        let analysis = result.expect("A move should be found");
+        assert_eq!(analysis.depth(), 1);
+    }
+
+    #[test]
+    fn should_stop_deepening_when_cancelled() {
+        // Given
+        let board = Board::default();
+        let strategy = NegamaxStrategy::new(60).with_budget(Budget::Time(Duration::MAX));
+        let control = SearchControl::default();
+        control.cancel();
+
+        // When
+        let result = strategy.search(&board, &control, strategy.budget);
+
+        // Then
+        let (_, _, depth) = result.expect("A move should be found");
+        assert_eq!(depth, 1);
+    }
+
+    #[test]
+    fn should_report_progress_of_deepest_search() {
+        // Given
+        let board = Board::from_transcript("f5d6c3d3c4").expect("Transcript should be legal");
+        let strategy = NegamaxStrategy::new(3).with_budget(Budget::Nodes(u64::MAX));
+        let control = SearchControl::default();
+
+        // When
+        let result = strategy.select_move_controlled(&board, &control);
+
+        // Then
+        let progress = control.progress().expect("Progress should be reported");
+        assert_eq!(progress.depth, 3);
+        assert_eq!(Some(progress.best_move), result);
+        assert!(progress.nodes > 0);
+    }
+
+    #[test]
+    fn should_fall_back_to_search_when_solver_runs_out_of_budget() {
+        // Given
+        let mut rng = fastrand::Rng::with_seed(5);
+        let mut board = Board::default();
+        while board.empties() > 18 {
+            let moves = board.available_positions(board.current_player());
+            let (x, y) = moves[rng.usize(0..moves.len())];
+            board.place(x, y).expect("Move should be legal");
+        }
+        let strategy = NegamaxStrategy::new(3)
+            .with_budget(Budget::Nodes(2_000))
+            .with_endgame_solver(EndgameSolver::new(20));
+        let control = SearchControl::default();
+
+        // When
+        let result = strategy.select_move_controlled(&board, &control);
+
+        // Then
+        let progress = control.progress().expect("Progress should be reported");
+        assert!(progress.depth <= 3);
+        assert_eq!(Some(progress.best_move), result);
+        assert!(
+            board
+                .available_positions(board.current_player())
+                .contains(&progress.best_move)
+        );
+    }
+
+    #[test]
+    fn should_deepen_over_several_calls_up_to_maximum_depth() {
+        // Given
+        let board = Board::from_transcript("f5d6c3d3c4").expect("Transcript should be legal");
+        let strategy = NegamaxStrategy::new(5).with_budget(Budget::Nodes(200));
+        let mut deepening = Deepening::default();
+
+        // When
+        let mut calls = 0;
+        while !deepening.is_finished() {
+            strategy.deepen(&board, &mut deepening, &SearchControl::default());
+            calls += 1;
+        }
+
+        // Then
+        assert!(calls > 1);
+        let (position, _, depth) = deepening.best.expect("A move should be found");
+        assert_eq!(depth, 5);
+        assert!(
+            board
+                .available_positions(board.current_player())
+                .contains(&position)
+        );
+    }
+
+    #[test]
+    fn should_finish_deepening_once_the_end_is_solved() {
+        // Given
+        let mut rng = fastrand::Rng::with_seed(5);
+        let mut board = Board::default();
+        while board.empties() > 10 {
+            let moves = board.available_positions(board.current_player());
+            let (x, y) = moves[rng.usize(0..moves.len())];
+            board.place(x, y).expect("Move should be legal");
+        }
+        let solver = EndgameSolver::new(board.empties());
+        let strategy = NegamaxStrategy::new(2)
+            .with_budget(Budget::Nodes(u64::MAX))
+            .with_endgame_solver(EndgameSolver::new(board.empties()));
+        let mut deepening = Deepening::default();
+
+        // When
+        strategy.deepen(&board, &mut deepening, &SearchControl::default());
+
+        // Then
+        assert!(deepening.is_finished());
+        let solution = solver.solve(&board).expect("Position should be solved");
+        assert_eq!(deepening.best_move(), Some(solution.position()));
+    }
+
+    #[test]
+    fn should_return_none_when_no_move_is_available() {
+        // Given
+        let board: Board = format!("{} X", "O".repeat(64))
+            .parse()
+            .expect("Position should be valid");
+
+        // When / Then
+        assert_eq!(NegamaxStrategy::new(2).select_move(&board), None);
+    }
+}
@@ -0,0 +1,15 @@
+use crate::domain::board::Board;
+use crate::domain::search::SearchStrategy;
+
+/// Plays any legal move.
+pub struct RandomStrategy {}
+
+impl SearchStrategy for RandomStrategy {
+    fn select_move(&self, board: &Board) -> Option<(usize, usize)> {
+        let available_moves = board.available_positions(board.current_player());
+        if available_moves.is_empty() {
+            return None;
+        }
+        Some(available_moves[fastrand::usize(0..available_moves.len())])
+    }
+}
@@ -0,0 +1,151 @@
+/// Kind of score stored for a position, depending on how the alpha-beta window cut the search.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Bound {
+    /// The score is exact.
+    Exact,
+    /// The real score is at least this one: the search stopped on a cutoff.
+    Lower,
+    /// The real score is at most this one: no move reached alpha.
+    Upper,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Entry {
+    pub key: u64,
+    /// Remaining depth the position was searched with.
+    pub depth: u8,
+    pub score: i32,
+    pub bound: Bound,
+    pub best_move: Option<(usize, usize)>,
+}
+
+#[derive(Copy, Clone)]
+struct Slot {
+    entry: Entry,
+    generation: u8,
+}
+
+/// Fixed-size table of searched positions, indexed by their Zobrist hash. When two positions
+/// share a slot, entries left by a previous search are replaced first, then the deepest one is
+/// kept.
+pub struct TranspositionTable {
+    slots: Vec<Option<Slot>>,
+    generation: u8,
+}
+
+impl TranspositionTable {
+    /// Table holding `capacity` entries, rounded up to a power of two.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            slots: vec![None; capacity.max(1).next_power_of_two()],
+            generation: 0,
+        }
+    }
+
+    fn index(&self, key: u64) -> usize {
+        key as usize & (self.slots.len() - 1)
+    }
+
+    pub fn probe(&self, key: u64) -> Option<&Entry> {
+        self.slots[self.index(key)]
+            .as_ref()
+            .map(|slot| &slot.entry)
+            .filter(|entry| entry.key == key)
+    }
+
+    pub fn store(&mut self, entry: Entry) {
+        let generation = self.generation;
+        let index = self.index(entry.key);
+        let slot = &mut self.slots[index];
+        let replace = match slot {
+            None => true,
+            Some(slot) => {
+                slot.entry.key == entry.key
+                    || slot.generation != generation
+                    || entry.depth >= slot.entry.depth
+            }
+        };
+        if replace {
+            *slot = Some(Slot { entry, generation });
+        }
+    }
+
+    /// Marks the entries stored until now as left by a previous search.
+    pub fn new_search(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.fill(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(key: u64, depth: u8) -> Entry {
+        Entry {
+            key,
+            depth,
+            score: 10,
+            bound: Bound::Exact,
+            best_move: Some((2, 3)),
+        }
+    }
+
+    #[test]
+    fn should_find_stored_entry() {
+        // Given
+        let mut table = TranspositionTable::new(16);
+
+        // When
+        table.store(entry(42, 3));
+
+        // Then
+        assert_eq!(table.probe(42), Some(&entry(42, 3)));
+        assert_eq!(table.probe(42 + 16), None, "same slot but another position");
+    }
+
+    #[test]
+    fn should_keep_deepest_entry_of_current_search() {
+        // Given
+        let mut table = TranspositionTable::new(16);
+        table.store(entry(1, 5));
+
+        // When
+        table.store(entry(17, 2));
+
+        // Then
+        assert_eq!(table.probe(1), Some(&entry(1, 5)));
+        assert_eq!(table.probe(17), None);
+    }
+
+    #[test]
+    fn should_replace_entries_of_previous_search() {
+        // Given
+        let mut table = TranspositionTable::new(16);
+        table.store(entry(1, 5));
+        table.new_search();
+
+        // When
+        table.store(entry(17, 2));
+
+        // Then
+        assert_eq!(table.probe(1), None);
+        assert_eq!(table.probe(17), Some(&entry(17, 2)));
+    }
+
+    #[test]
+    fn should_forget_everything_when_cleared() {
+        // Given
+        let mut table = TranspositionTable::new(16);
+        table.store(entry(1, 5));
+
+        // When
+        table.clear();
+
+        // Then
+        assert_eq!(table.probe(1), None);
+    }
+}
@@ -0,0 +1,187 @@
+//! The 8 symmetries of the board: rotations and reflections leave the rules unchanged, so
+//! a position and its images are worth the same. The canonical form picks one image of each
+//! position, to recognize it whichever way it was reached.
+
+use crate::domain::bitboard;
+use crate::domain::board::{Board, ColorPiece};
+use crate::domain::moves::Move;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Symmetry {
+    Identity,
+    /// Quarter turn clockwise.
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    /// Columns `a` and `h` swapped.
+    FlipHorizontal,
+    /// Rows `1` and `8` swapped.
+    FlipVertical,
+    /// Reflection across the `a1`-`h8` diagonal.
+    FlipDiagonal,
+    /// Reflection across the `h1`-`a8` diagonal.
+    FlipAntiDiagonal,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::FlipDiagonal,
+        Symmetry::FlipAntiDiagonal,
+    ];
+
+    /// Image of the cell `(x, y)`.
+    pub fn apply(self, (x, y): (usize, usize)) -> (usize, usize) {
+        match self {
+            Symmetry::Identity => (x, y),
+            Symmetry::Rotate90 => (7 - y, x),
+            Symmetry::Rotate180 => (7 - x, 7 - y),
+            Symmetry::Rotate270 => (y, 7 - x),
+            Symmetry::FlipHorizontal => (7 - x, y),
+            Symmetry::FlipVertical => (x, 7 - y),
+            Symmetry::FlipDiagonal => (y, x),
+            Symmetry::FlipAntiDiagonal => (7 - y, 7 - x),
+        }
+    }
+
+    /// Symmetry which undoes this one.
+    pub fn inverse(self) -> Symmetry {
+        match self {
+            Symmetry::Rotate90 => Symmetry::Rotate270,
+            Symmetry::Rotate270 => Symmetry::Rotate90,
+            symmetry => symmetry,
+        }
+    }
+
+    /// Image of a move, a pass staying a pass.
+    pub fn apply_move(self, mv: Move) -> Move {
+        match mv {
+            Move::Play(x, y) => {
+                let (x, y) = self.apply((x, y));
+                Move::Play(x, y)
+            }
+            Move::Pass => Move::Pass,
+        }
+    }
+
+    fn apply_bitboard(self, bitboard: u64) -> u64 {
+        bitboard::Squares(bitboard)
+            .map(|cell| self.apply(cell))
+            .fold(0, |image, (x, y)| image | bitboard::square(x, y))
+    }
+
+    /// Image of the position, with the same color to move and without history.
+    pub fn apply_board(self, board: &Board) -> Board {
+        Board::with_position(
+            self.apply_bitboard(board.pieces(ColorPiece::Black)),
+            self.apply_bitboard(board.pieces(ColorPiece::White)),
+            board.current_player().color(),
+        )
+    }
+}
+
+impl Board {
+    /// Image of the position shared by all its symmetries, along with the symmetry which
+    /// gives it from this board. Moves found on the canonical board come back to this one
+    /// with the inverse symmetry.
+    pub fn canonical(&self) -> (Board, Symmetry) {
+        Symmetry::ALL
+            .into_iter()
+            .map(|symmetry| (symmetry.apply_board(self), symmetry))
+            .min_by_key(|(image, _)| {
+                (
+                    image.pieces(ColorPiece::Black),
+                    image.pieces(ColorPiece::White),
+                )
+            })
+            .expect("There should be symmetries")
+    }
+
+    /// Hash of the canonical form, the same for all the symmetries of the position.
+    pub fn canonical_hash(&self) -> u64 {
+        self.canonical().0.hash()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_undo_each_symmetry_with_its_inverse() {
+        for symmetry in Symmetry::ALL {
+            for index in 0..64 {
+                // Given
+                let cell = (index % 8, index / 8);
+
+                // When
+                let image = symmetry.apply(cell);
+
+                // Then
+                assert_eq!(symmetry.inverse().apply(image), cell, "{:?}", symmetry);
+            }
+        }
+    }
+
+    #[test]
+    fn should_map_first_moves_onto_each_other() {
+        // Given
+        let board = Board::from_transcript("f5").expect("Transcript should be legal");
+
+        // When
+        let images: Vec<String> = Symmetry::ALL
+            .iter()
+            .map(|symmetry| symmetry.apply_board(&board).to_string())
+            .collect();
+
+        // Then
+        for transcript in ["f5", "e6", "d3", "c4"] {
+            let other = Board::from_transcript(transcript).expect("Transcript should be legal");
+            assert!(images.contains(&other.to_string()), "{}", transcript);
+        }
+    }
+
+    #[test]
+    fn should_give_same_canonical_form_to_the_four_first_moves() {
+        // Given
+        let boards: Vec<Board> = ["f5", "e6", "d3", "c4"]
+            .iter()
+            .map(|transcript| {
+                Board::from_transcript(transcript).expect("Transcript should be legal")
+            })
+            .collect();
+
+        // When
+        let hashes: Vec<u64> = boards.iter().map(Board::canonical_hash).collect();
+
+        // Then
+        assert!(hashes.iter().all(|hash| *hash == hashes[0]));
+        assert_ne!(hashes[0], Board::default().canonical_hash());
+    }
+
+    #[test]
+    fn should_bring_canonical_moves_back_with_inverse_symmetry() {
+        // Given
+        let board = Board::from_transcript("d3c5").expect("Transcript should be legal");
+        let (canonical, symmetry) = board.canonical();
+        assert_eq!(
+            symmetry.apply_board(&board).to_string(),
+            canonical.to_string()
+        );
+
+        for (x, y) in canonical.available_positions(canonical.current_player()) {
+            // When
+            let back = symmetry.inverse().apply_move(Move::Play(x, y));
+
+            // Then
+            let mut played = board.clone();
+            played.play(back).expect("Move should be legal");
+        }
+        assert_eq!(symmetry.apply_move(Move::Pass), Move::Pass);
+    }
+}
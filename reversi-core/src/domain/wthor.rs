@@ -0,0 +1,486 @@
+//! WTHOR, the binary format of the French Othello federation archive of tournament games.
+//!
+//! Every file starts with a 16-byte header giving the number of records. Games (`.wtb`) are
+//! 68-byte records: tournament, black and white player numbers, the final number of black
+//! discs, the theoretical score and 60 moves written `10 * row + column` (`f5` is 56), 0 once
+//! the game is over. Players (`.jou`) and tournaments (`.trn`) are names of 20 and 26 bytes.
+
+use crate::domain::board::Board;
+use crate::domain::moves::{Move, MoveError};
+use crate::domain::symmetry::Symmetry;
+use std::fmt;
+
+const HEADER_SIZE: usize = 16;
+const GAME_SIZE: usize = 68;
+const PLAYER_SIZE: usize = 20;
+const TOURNAMENT_SIZE: usize = 26;
+/// Black discs of a drawn game.
+const DRAW_SCORE: u8 = 32;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum WthorError {
+    /// The file ends before the records announced by its header.
+    TooShort { expected: usize, found: usize },
+    /// The games are not played on an 8x8 board.
+    UnsupportedBoard(u8),
+    /// The move of this game, at this index, is not a cell of the board.
+    InvalidMove { game: usize, index: usize, code: u8 },
+    /// The move of this game, at this index, cannot be played.
+    IllegalMove {
+        game: usize,
+        index: usize,
+        mv: Move,
+        reason: MoveError,
+    },
+}
+
+impl fmt::Display for WthorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WthorError::TooShort { expected, found } => {
+                write!(f, "expected {} bytes, found {}", expected, found)
+            }
+            WthorError::UnsupportedBoard(size) => write!(f, "unsupported board size {}", size),
+            WthorError::InvalidMove { game, index, code } => {
+                write!(
+                    f,
+                    "game {}: invalid move code {} at index {}",
+                    game, code, index
+                )
+            }
+            WthorError::IllegalMove {
+                game,
+                index,
+                mv,
+                reason,
+            } => write!(
+                f,
+                "game {}: illegal move {} at index {}: {}",
+                game, mv, index, reason
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WthorError {}
+
+/// Game of the archive, its moves already checked by replaying them.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WthorGame {
+    tournament: u16,
+    black: u16,
+    white: u16,
+    black_discs: u8,
+    theoretical_black_discs: u8,
+    moves: Vec<(usize, usize)>,
+    /// Hash of the board before each move and after the last one, for the searches.
+    hashes: Vec<u64>,
+}
+
+impl WthorGame {
+    /// Number of the tournament, an index in the tournaments file.
+    pub fn tournament(&self) -> u16 {
+        self.tournament
+    }
+
+    /// Number of the black player, an index in the players file.
+    pub fn black(&self) -> u16 {
+        self.black
+    }
+
+    pub fn white(&self) -> u16 {
+        self.white
+    }
+
+    /// Black discs at the end of the game, the empty cells going to the winner.
+    pub fn black_discs(&self) -> u8 {
+        self.black_discs
+    }
+
+    /// Black discs with a perfect play from the point the archive solved the game.
+    pub fn theoretical_black_discs(&self) -> u8 {
+        self.theoretical_black_discs
+    }
+
+    /// Moves of both players, the passes being left out.
+    pub fn moves(&self) -> &[(usize, usize)] {
+        &self.moves
+    }
+
+    /// Board after the first `count` moves.
+    pub fn replay(&self, count: usize) -> Board {
+        let mut board = Board::default();
+        for &(x, y) in self.moves.iter().take(count) {
+            board.place(x, y).expect("Moves should have been checked");
+        }
+        board
+    }
+}
+
+/// Results of a set of games, from the final number of black discs.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Statistics {
+    pub black_wins: u32,
+    pub white_wins: u32,
+    pub draws: u32,
+}
+
+impl Statistics {
+    pub fn games(&self) -> u32 {
+        self.black_wins + self.white_wins + self.draws
+    }
+
+    fn add(&mut self, game: &WthorGame) {
+        match game.black_discs.cmp(&DRAW_SCORE) {
+            std::cmp::Ordering::Greater => self.black_wins += 1,
+            std::cmp::Ordering::Less => self.white_wins += 1,
+            std::cmp::Ordering::Equal => self.draws += 1,
+        }
+    }
+}
+
+/// Games of a `.wtb` file, those whose moves cannot be replayed being left out.
+#[derive(PartialEq, Eq, Debug, Default)]
+pub struct WthorGames {
+    pub games: Vec<WthorGame>,
+    /// Why each record left out was, so that one bad record does not lose the whole file.
+    pub skipped: Vec<WthorError>,
+}
+
+/// Games of the archive reaching a position.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct PositionSearch {
+    /// Indexes of the games in the database.
+    pub games: Vec<usize>,
+    pub statistics: Statistics,
+    /// Moves played next in these games, on the searched board, most played first.
+    pub next_moves: Vec<((usize, usize), Statistics)>,
+}
+
+/// Games of the archive with the names of their players and tournaments.
+#[derive(Default)]
+pub struct WthorDatabase {
+    games: Vec<WthorGame>,
+    players: Vec<String>,
+    tournaments: Vec<String>,
+}
+
+/// Records of a file, after checking that the file holds as many as its header announces.
+fn records(bytes: &[u8], size: usize, count: usize) -> Result<Vec<&[u8]>, WthorError> {
+    let expected = HEADER_SIZE + size * count;
+    if bytes.len() < expected {
+        return Err(WthorError::TooShort {
+            expected,
+            found: bytes.len(),
+        });
+    }
+    Ok(bytes[HEADER_SIZE..expected].chunks(size).collect())
+}
+
+fn header(bytes: &[u8]) -> Result<&[u8], WthorError> {
+    bytes.get(..HEADER_SIZE).ok_or(WthorError::TooShort {
+        expected: HEADER_SIZE,
+        found: bytes.len(),
+    })
+}
+
+fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+/// Games of a `.wtb` file, each one replayed to check its moves.
+pub fn read_games(bytes: &[u8]) -> Result<WthorGames, WthorError> {
+    let header = header(bytes)?;
+    let count = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+    match header[12] {
+        0 | 8 => {}
+        size => return Err(WthorError::UnsupportedBoard(size)),
+    }
+
+    let mut games = WthorGames::default();
+    for (game, record) in records(bytes, GAME_SIZE, count)?.into_iter().enumerate() {
+        match read_game(game, record) {
+            Ok(read) => games.games.push(read),
+            Err(error) => games.skipped.push(error),
+        }
+    }
+    Ok(games)
+}
+
+/// Game of the record numbered `game` in its file.
+fn read_game(game: usize, record: &[u8]) -> Result<WthorGame, WthorError> {
+    let mut board = Board::default();
+    let mut moves = Vec::new();
+    let mut hashes = vec![board.hash()];
+    for (index, &code) in record[8..].iter().enumerate() {
+        if code == 0 {
+            break;
+        }
+        let (row, column) = (code / 10, code % 10);
+        if !(1..=8).contains(&row) || !(1..=8).contains(&column) {
+            return Err(WthorError::InvalidMove { game, index, code });
+        }
+        let (x, y) = (column as usize - 1, row as usize - 1);
+        board
+            .place(x, y)
+            .map_err(|reason| WthorError::IllegalMove {
+                game,
+                index,
+                mv: Move::Play(x, y),
+                reason,
+            })?;
+        moves.push((x, y));
+        hashes.push(board.hash());
+    }
+    Ok(WthorGame {
+        tournament: u16_at(record, 0),
+        black: u16_at(record, 2),
+        white: u16_at(record, 4),
+        black_discs: record[6],
+        theoretical_black_discs: record[7],
+        moves,
+        hashes,
+    })
+}
+
+/// Names of a `.jou` file of players.
+pub fn read_players(bytes: &[u8]) -> Result<Vec<String>, WthorError> {
+    read_names(bytes, PLAYER_SIZE)
+}
+
+/// Names of a `.trn` file of tournaments.
+pub fn read_tournaments(bytes: &[u8]) -> Result<Vec<String>, WthorError> {
+    read_names(bytes, TOURNAMENT_SIZE)
+}
+
+/// Names padded with zeros, written in Latin-1.
+fn read_names(bytes: &[u8], size: usize) -> Result<Vec<String>, WthorError> {
+    let count = u16_at(header(bytes)?, 8) as usize;
+    Ok(records(bytes, size, count)?
+        .into_iter()
+        .map(|record| {
+            record
+                .iter()
+                .take_while(|&&byte| byte != 0)
+                .map(|&byte| byte as char)
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect())
+}
+
+impl WthorDatabase {
+    pub fn new(games: Vec<WthorGame>, players: Vec<String>, tournaments: Vec<String>) -> Self {
+        Self {
+            games,
+            players,
+            tournaments,
+        }
+    }
+
+    pub fn games(&self) -> &[WthorGame] {
+        &self.games
+    }
+
+    pub fn player(&self, number: u16) -> Option<&str> {
+        self.players.get(number as usize).map(String::as_str)
+    }
+
+    pub fn tournament(&self, number: u16) -> Option<&str> {
+        self.tournaments.get(number as usize).map(String::as_str)
+    }
+
+    /// Games reaching the position or one of its symmetries. Each move adds a disc, so the
+    /// position can only be reached after as many moves as it has discs more than the
+    /// initial position. The games were replayed once when read, only their hashes are
+    /// compared.
+    pub fn search(&self, board: &Board) -> PositionSearch {
+        let Some(plies) = (64 - board.empties() as usize).checked_sub(4) else {
+            return PositionSearch::default();
+        };
+        let images: Vec<(Symmetry, u64)> = Symmetry::ALL
+            .into_iter()
+            .map(|symmetry| (symmetry, symmetry.apply_board(board).hash()))
+            .collect();
+
+        let mut search = PositionSearch::default();
+        for (index, game) in self.games.iter().enumerate() {
+            let Some(&hash) = game.hashes.get(plies) else {
+                continue;
+            };
+            let Some((symmetry, _)) = images.iter().find(|(_, image)| *image == hash) else {
+                continue;
+            };
+            search.games.push(index);
+            search.statistics.add(game);
+            if let Some(&position) = game.moves.get(plies) {
+                // Back from the game to the searched board
+                let position = symmetry.inverse().apply(position);
+                match search
+                    .next_moves
+                    .iter_mut()
+                    .find(|(other, _)| *other == position)
+                {
+                    Some((_, statistics)) => statistics.add(game),
+                    None => {
+                        let mut statistics = Statistics::default();
+                        statistics.add(game);
+                        search.next_moves.push((position, statistics));
+                    }
+                }
+            }
+        }
+        search.next_moves.sort_by_key(|(position, statistics)| {
+            (std::cmp::Reverse(statistics.games()), *position)
+        });
+        search
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::notation::parse_transcript;
+
+    fn header(records: u32, names: u16) -> Vec<u8> {
+        let mut bytes = vec![20, 24, 1, 1];
+        bytes.extend(records.to_le_bytes());
+        bytes.extend(names.to_le_bytes());
+        bytes.extend(2024u16.to_le_bytes());
+        bytes.extend([8, 0, 0, 0]);
+        bytes
+    }
+
+    fn games_file(games: &[(&str, u8)]) -> Vec<u8> {
+        let mut bytes = header(games.len() as u32, 0);
+        for (number, (transcript, black_discs)) in games.iter().enumerate() {
+            bytes.extend(1u16.to_le_bytes());
+            bytes.extend((number as u16).to_le_bytes());
+            bytes.extend((number as u16 + 1).to_le_bytes());
+            bytes.extend([*black_discs, *black_discs]);
+            let mut moves = [0u8; 60];
+            for (index, mv) in parse_transcript(transcript)
+                .expect("Transcript should be valid")
+                .into_iter()
+                .enumerate()
+            {
+                if let Move::Play(x, y) = mv {
+                    moves[index] = (10 * (y + 1) + x + 1) as u8;
+                }
+            }
+            bytes.extend(moves);
+        }
+        bytes
+    }
+
+    #[test]
+    fn should_read_games_and_their_moves() {
+        // Given
+        let bytes = games_file(&[("f5d6c3", 40), ("c4e3", 20)]);
+
+        // When
+        let games = read_games(&bytes).expect("File should be valid").games;
+
+        // Then
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].moves(), &[(5, 4), (3, 5), (2, 2)]);
+        assert_eq!(games[0].black_discs(), 40);
+        assert_eq!(
+            (games[1].tournament(), games[1].black(), games[1].white()),
+            (1, 1, 2)
+        );
+    }
+
+    #[test]
+    fn should_read_names() {
+        // Given
+        let mut bytes = header(0, 2);
+        for name in ["Tastet Marc", "Caspard Stéphane"] {
+            let mut record = [0u8; PLAYER_SIZE];
+            // Latin-1
+            let latin1: Vec<u8> = name.chars().map(|c| c as u8).collect();
+            record[..latin1.len()].copy_from_slice(&latin1);
+            bytes.extend(record);
+        }
+
+        // When
+        let players = read_players(&bytes).expect("File should be valid");
+
+        // Then
+        assert_eq!(players, vec!["Tastet Marc", "Caspard Stéphane"]);
+    }
+
+    #[test]
+    fn should_report_truncated_files() {
+        let bytes = games_file(&[("f5d6", 33)]);
+        assert_eq!(
+            read_games(&bytes[..40]),
+            Err(WthorError::TooShort {
+                expected: 84,
+                found: 40
+            })
+        );
+    }
+
+    #[test]
+    fn should_skip_games_with_illegal_or_invalid_moves() {
+        // Given
+        let mut bytes = games_file(&[("f5d6", 33), ("f5d6", 33), ("c4e3", 20)]);
+        bytes[HEADER_SIZE + 9] = 11;
+        bytes[HEADER_SIZE + GAME_SIZE + 9] = 99;
+
+        // When
+        let games = read_games(&bytes).expect("File should be readable");
+
+        // Then
+        assert_eq!(games.games.len(), 1);
+        assert_eq!(games.games[0].moves(), &[(2, 3), (4, 2)]);
+        assert_eq!(
+            games.skipped,
+            vec![
+                WthorError::IllegalMove {
+                    game: 0,
+                    index: 1,
+                    mv: Move::Play(0, 0),
+                    reason: MoveError::NoFlip
+                },
+                WthorError::InvalidMove {
+                    game: 1,
+                    index: 1,
+                    code: 99
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_games_reaching_position_under_any_symmetry() {
+        // Given: d3c5 is f5d6 reflected across the h1-a8 diagonal
+        let bytes = games_file(&[("f5d6c3", 40), ("d3c5f6", 20), ("f5d6c5", 32), ("f5f6", 50)]);
+        let database = WthorDatabase::new(
+            read_games(&bytes).expect("File should be valid").games,
+            Vec::new(),
+            Vec::new(),
+        );
+        let board = Board::from_transcript("f5d6").expect("Transcript should be legal");
+
+        // When
+        let search = database.search(&board);
+
+        // Then
+        assert_eq!(search.games, vec![0, 1, 2]);
+        assert_eq!(
+            search.statistics,
+            Statistics {
+                black_wins: 1,
+                white_wins: 1,
+                draws: 1
+            }
+        );
+        // f6 after d3c5 is c3 after f5d6
+        assert_eq!(search.next_moves[0].0, (2, 2));
+        assert_eq!(search.next_moves[0].1.games(), 2);
+        assert_eq!(search.next_moves[1].0, (2, 4));
+    }
+}
@@ -0,0 +1,66 @@
+// Zobrist keys: one random number per color and cell, plus one when white is to move. The hash
+// of a position XORs the keys of its pieces, so it can be updated piece by piece.
+
+use crate::domain::bitboard::Squares;
+use crate::domain::board::ColorPiece;
+use crate::domain::board::ColorPiece::{Black, White};
+
+const fn generate_keys() -> [[u64; 64]; 2] {
+    // SplitMix64, with a fixed seed so hashes do not change from one run to the other
+    let mut state: u64 = 0x5eed_0f0b_e110_0000;
+    let mut keys = [[0; 64]; 2];
+    let mut index = 0;
+    while index < 128 {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        keys[index / 64][index % 64] = z ^ (z >> 31);
+        index += 1;
+    }
+    keys
+}
+
+const KEYS: [[u64; 64]; 2] = generate_keys();
+pub(crate) const WHITE_TO_MOVE: u64 = 0x2d35_8dcc_aa6c_78a5;
+
+/// Key of the pieces of a color on the given cells.
+pub(crate) fn pieces(color: ColorPiece, bitboard: u64) -> u64 {
+    let keys = match color {
+        Black => &KEYS[0],
+        White => &KEYS[1],
+    };
+    Squares(bitboard).fold(0, |hash, (x, y)| hash ^ keys[y * 8 + x])
+}
+
+/// Change of the hash when the pieces on the given cells change color.
+pub(crate) fn flips(bitboard: u64) -> u64 {
+    pieces(Black, bitboard) ^ pieces(White, bitboard)
+}
+
+pub(crate) fn hash(black: u64, white: u64, color_to_move: ColorPiece) -> u64 {
+    let side = match color_to_move {
+        Black => 0,
+        White => WHITE_TO_MOVE,
+    };
+    pieces(Black, black) ^ pieces(White, white) ^ side
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_generate_distinct_keys() {
+        // Given
+        let mut keys: Vec<u64> = KEYS.iter().flatten().copied().collect();
+        keys.push(WHITE_TO_MOVE);
+
+        // When
+        keys.sort();
+        keys.dedup();
+
+        // Then
+        assert_eq!(keys.len(), 129);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::application::game_record::GameRecord;
+use crate::application::game_repository::{GameRepository, RepositoryError, check_id};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const EXTENSION: &str = "json";
+
+/// Games stored as one JSON file per game, named after its id, in a directory created on the
+/// first save.
+pub struct FileGameRepository {
+    directory: PathBuf,
+}
+
+impl FileGameRepository {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn path(&self, id: &str) -> Result<PathBuf, RepositoryError> {
+        check_id(id)?;
+        Ok(self.directory.join(id).with_extension(EXTENSION))
+    }
+}
+
+fn storage_error(error: io::Error) -> RepositoryError {
+    RepositoryError::Storage(error.to_string())
+}
+
+fn io_error(id: &str, error: io::Error) -> RepositoryError {
+    match error.kind() {
+        io::ErrorKind::NotFound => RepositoryError::NotFound(id.to_string()),
+        _ => storage_error(error),
+    }
+}
+
+impl GameRepository for FileGameRepository {
+    fn save(&self, id: &str, record: &GameRecord) -> Result<(), RepositoryError> {
+        let path = self.path(id)?;
+        let json = serde_json::to_string_pretty(record)
+            .map_err(|error| RepositoryError::Storage(error.to_string()))?;
+        fs::create_dir_all(&self.directory).map_err(storage_error)?;
+        // Written aside then renamed, so that a crash never leaves half a game
+        let temporary = path.with_extension("tmp");
+        fs::write(&temporary, json).map_err(storage_error)?;
+        fs::rename(&temporary, &path).map_err(storage_error)
+    }
+
+    fn load(&self, id: &str) -> Result<GameRecord, RepositoryError> {
+        let json = fs::read_to_string(self.path(id)?).map_err(|error| io_error(id, error))?;
+        serde_json::from_str(&json).map_err(|error| RepositoryError::Corrupted {
+            id: id.to_string(),
+            message: error.to_string(),
+        })
+    }
+
+    fn list(&self) -> Result<Vec<String>, RepositoryError> {
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            // Nothing saved yet
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(storage_error(error)),
+        };
+        let mut ids = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(storage_error)?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == EXTENSION)
+                && let Some(id) = path.file_stem().and_then(|stem| stem.to_str())
+                && check_id(id).is_ok()
+            {
+                ids.push(id.to_string());
+            }
+        }
+        ids.sort();
+        Ok(ids)
+    }
+
+    fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        fs::remove_file(self.path(id)?).map_err(|error| io_error(id, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::seats::Seats;
+    use crate::domain::board::Board;
+
+    /// Directory of its own for each test, emptied first
+    fn repository(name: &str) -> FileGameRepository {
+        let directory =
+            std::env::temp_dir().join(format!("reversi-core-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&directory);
+        FileGameRepository::new(directory)
+    }
+
+    fn record(transcript: &str) -> GameRecord {
+        let board = Board::from_transcript(transcript).expect("Transcript should be legal");
+        GameRecord::new(&board, Seats::default())
+    }
+
+    #[test]
+    fn should_store_one_file_per_game() {
+        // Given
+        let repository = repository("files");
+        let game = record("f5d6c3");
+
+        // When
+        repository
+            .save("second", &record("f5"))
+            .expect("Game should be saved");
+        repository
+            .save("first", &game)
+            .expect("Game should be saved");
+
+        // Then
+        assert!(repository.directory().join("first.json").is_file());
+        assert_eq!(
+            repository.list(),
+            Ok(vec!["first".to_string(), "second".to_string()])
+        );
+        assert!(repository.load("first").is_ok_and(|loaded| loaded == game));
+        let _ = fs::remove_dir_all(repository.directory());
+    }
+
+    #[test]
+    fn should_delete_games_and_report_missing_ones() {
+        // Given
+        let repository = repository("delete");
+        assert_eq!(repository.list(), Ok(Vec::new()));
+        repository
+            .save("game", &record("f5"))
+            .expect("Game should be saved");
+
+        // When
+        let deleted = repository.delete("game");
+
+        // Then
+        assert_eq!(deleted, Ok(()));
+        assert_eq!(
+            repository.load("game").err(),
+            Some(RepositoryError::NotFound("game".to_string()))
+        );
+        assert_eq!(
+            repository.delete("game"),
+            Err(RepositoryError::NotFound("game".to_string()))
+        );
+        let _ = fs::remove_dir_all(repository.directory());
+    }
+
+    #[test]
+    fn should_report_corrupted_files() {
+        // Given
+        let repository = repository("corrupted");
+        fs::create_dir_all(repository.directory()).expect("Directory should be created");
+        fs::write(repository.directory().join("broken.json"), "{").expect("File should be written");
+
+        // When
+        let loaded = repository.load("broken");
+
+        // Then
+        assert!(matches!(
+            loaded.err(),
+            Some(RepositoryError::Corrupted { id, .. }) if id == "broken"
+        ));
+        let _ = fs::remove_dir_all(repository.directory());
+    }
+}
@@ -1,2 +1,6 @@
 pub mod application;
 pub mod domain;
+pub mod presentation;
+/// Storage backed by the file system, the records being written as JSON
+#[cfg(feature = "serde")]
+pub mod infrastructure;
@@ -0,0 +1,41 @@
+//! Texts shown to the player, shared by the frontends so that they say the same thing. The
+//! frontends speak French.
+
+use crate::application::difficulty::Difficulty;
+use crate::domain::board::ColorPiece;
+use crate::domain::moves::MoveError;
+
+pub fn difficulty_label(difficulty: Difficulty) -> &'static str {
+    match difficulty {
+        Difficulty::Beginner => "Débutant",
+        Difficulty::Intermediate => "Intermédiaire",
+        Difficulty::Advanced => "Confirmé",
+        Difficulty::Expert => "Expert",
+    }
+}
+
+pub fn color_name(color: ColorPiece) -> &'static str {
+    match color {
+        ColorPiece::Black => "Noir",
+        ColorPiece::White => "Blanc",
+    }
+}
+
+/// Notice of the player who has to pass.
+pub fn pass_message(color: ColorPiece) -> &'static str {
+    match color {
+        ColorPiece::Black => "Noir passe",
+        ColorPiece::White => "Blanc passe",
+    }
+}
+
+/// Why a move was refused.
+pub fn move_error_message(error: MoveError) -> &'static str {
+    match error {
+        MoveError::OutOfBounds => "Hors du plateau",
+        MoveError::Occupied => "Case occupée",
+        MoveError::NoFlip => "Aucun pion à retourner",
+        MoveError::GameOver => "Partie terminée",
+        MoveError::MustPlay => "Un coup est possible",
+    }
+}
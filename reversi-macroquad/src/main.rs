@@ -6,20 +6,32 @@ use macroquad::{
 use std::iter::repeat_n;
 
 use macroquad::prelude::*;
+use reversi_core::application::difficulty::Difficulty;
+use reversi_core::application::seats::{Controller, Seats};
 use reversi_core::application::use_case::UseCase;
 
 use ui::components::{create_board, create_pieces, draw_hint, draw_piece};
 use ui::fireworks::{spawn_firework, Particle};
 use reversi_core::domain::board::ColorPiece::{Black, White};
 use reversi_core::domain::board::{Board, ColorPiece};
+use reversi_core::domain::book::OpeningBook;
+use reversi_core::domain::search::{Budget, Deepening, NegamaxStrategy, SearchControl};
+use reversi_core::presentation::labels::{difficulty_label, move_error_message, pass_message};
 pub mod ui;
 
 const CELL_SIZE: f32 = 60f32;
 const BORDER_SIZE: f32 = 40f32;
 
+/// Pause avant le coup de l'IA, pour laisser voir le coup précédent
+const AI_MOVE_DELAY: f64 = 0.3;
+/// Positions cherchées par l'IA à chaque image. Sa recherche reprend d'une image à l'autre
+/// là où elle s'était arrêtée, sans figer l'affichage (il n'y a qu'un fil en wasm).
+const AI_NODES_PER_FRAME: u64 = 20_000;
+
 const GRID_COLOR: Color = Color::new(0.0, 0.4, 0.0, 1.); // Vert foncé Othello
 
 enum GameState {
+    Menu,
     Start,
     Playing(f64, Board),
     EndGame(EndGameState),
@@ -30,12 +42,21 @@ enum EndGameState {
         animation_start: f64,
         player1: usize,
         player2: usize,
+        winner: Option<ColorPiece>,
     },
     Fireworks(FireworkState),
     Lose(f64),
     Draw(f64),
 }
 
+/// Coup de l'IA en cours de réflexion
+struct AiTurn {
+    since: f64,
+    /// Coup tiré au hasard pour une erreur, ou pris dans le livre d'ouvertures
+    unsearched: Option<(usize, usize)>,
+    deepening: Deepening,
+}
+
 struct FireworkState {
     particles: Vec<Particle>,
     timer: f32,
@@ -65,15 +86,44 @@ fn window_conf() -> Conf {
 #[macroquad::main(window_conf)]
 async fn main() {
     let use_case = UseCase::default();
+    let mut difficulty = Difficulty::default();
 
     let reveal_delay = 0.1;
 
-    let mut state = GameState::Start;
+    let (controllers, start_position) = parse_args();
+    let mut seats = Seats::default();
+
+    let mut state = GameState::Menu;
+    let mut notice: Option<(&str, f64)> = None;
+    let book = OpeningBook::builtin();
+    let mut searches = Vec::new();
+    // Réflexion de l'IA, qui dure plusieurs images
+    let mut ai_turn: Option<AiTurn> = None;
 
     loop {
         match &mut state {
+            GameState::Menu => {
+                clear_background(GRID_COLOR);
+                if let Some(selected) = difficulty_menu(difficulty) {
+                    difficulty = selected;
+                    seats = seats_with_level(&controllers, difficulty);
+                    searches = frame_searches(&seats);
+                    state = GameState::Start;
+                }
+            }
             GameState::Start => {
-                let board = use_case.initialize_game_use_case.execute();
+                let board = if start_position.is_empty() {
+                    use_case.initialize_game_use_case.execute()
+                } else {
+                    use_case
+                        .initialize_game_use_case
+                        .execute_from_position(&start_position)
+                        .unwrap_or_else(|error| {
+                            eprintln!("Position ignorée : {}", error);
+                            use_case.initialize_game_use_case.execute()
+                        })
+                };
+                ai_turn = None;
                 state = GameState::Playing(get_time(), board);
             }
             GameState::Playing(start_time, board) => {
@@ -88,13 +138,21 @@ async fn main() {
                         animation_start: get_time(),
                         player1: score.player1(),
                         player2: score.player2(),
+                        winner: score.winner(),
                     });
                     continue;
                 }
 
+                if let Some((text, since)) = notice
+                    && get_time() - since < 1.5
+                {
+                    notice_screen(text);
+                }
+
                 let positions = use_case.compute_available_moves_use_case.execute(board);
+                let to_move = seats.to_move(board);
 
-                if board.player1() {
+                if to_move == Controller::Human {
                     for position in positions {
                         draw_hint(
                             BORDER_SIZE + position.0 as f32 * CELL_SIZE + CELL_SIZE / 2f32,
@@ -104,17 +162,60 @@ async fn main() {
                     }
                 }
 
-                if board.player1() && is_mouse_button_pressed(MouseButton::Left) {
+                if to_move == Controller::Human && is_key_pressed(KeyCode::Backspace) {
+                    // Annule jusqu'au dernier coup d'un joueur humain
+                    while board.undo().is_some() && seats.to_move(board) != Controller::Human {}
+                    *start_time = get_time();
+                } else if to_move == Controller::Human && is_key_pressed(KeyCode::Enter) {
+                    // Rejoue les coups annulés jusqu'au prochain tour d'un joueur humain
+                    while board.redo().is_some() && seats.to_move(board) != Controller::Human {}
+                    *start_time = get_time();
+                } else if to_move == Controller::Human && is_mouse_button_pressed(MouseButton::Left)
+                {
                     let (mouse_x, mouse_y) = mouse_position();
 
                     let x = ((mouse_x - BORDER_SIZE) / CELL_SIZE).floor() as usize;
                     let y = ((mouse_y - BORDER_SIZE) / CELL_SIZE).floor() as usize;
 
-                    use_case.play_move_use_case.execute(board, x, y);
-                    *start_time = get_time();
-                } else if board.player2() && get_time() - *start_time > 0.8 {
-                    use_case.play_ai_move_use_case.execute(board);
+                    notice = match use_case.play_move_use_case.execute(board, x, y) {
+                        Ok(outcome) => outcome
+                            .pass()
+                            .map(|color| (pass_message(color), get_time())),
+                        Err(error) => Some((move_error_message(error), get_time())),
+                    };
                     *start_time = get_time();
+                } else if let Controller::Ai(level) = to_move
+                    && get_time() - *start_time > AI_MOVE_DELAY
+                {
+                    // Le coup joué sans chercher n'est tiré qu'une fois par tour
+                    let turn = ai_turn.get_or_insert_with(|| AiTurn {
+                        since: get_time(),
+                        unsearched: level.unsearched_move(board, &book),
+                        deepening: Deepening::default(),
+                    });
+                    let color = board.current_player().color();
+                    let search = searches
+                        .iter()
+                        .find(|(searched, _)| *searched == color)
+                        .map(|(_, search)| search);
+                    if let (None, Some(search)) = (turn.unsearched, search) {
+                        search.deepen(board, &mut turn.deepening, &SearchControl::default());
+                    }
+                    let thought = turn.deepening.is_finished()
+                        || get_time() - turn.since >= level.time_budget().as_secs_f64();
+                    if turn.unsearched.is_some() || thought {
+                        let position = turn.unsearched.or(turn.deepening.best_move());
+                        notice = position.and_then(|position| {
+                            match use_case.play_ai_move_use_case.play(board, position) {
+                                Ok(selected_move) => selected_move
+                                    .pass()
+                                    .map(|color| (pass_message(color), get_time())),
+                                Err(error) => Some((move_error_message(error), get_time())),
+                            }
+                        });
+                        ai_turn = None;
+                        *start_time = get_time();
+                    }
                 }
             }
 
@@ -122,6 +223,7 @@ async fn main() {
                 animation_start,
                 player1,
                 player2,
+                winner,
             }) => {
                 clear_background(GRID_COLOR);
                 create_board();
@@ -130,19 +232,21 @@ async fn main() {
                     create_pieces_for_end_game(*animation_start, reveal_delay, *player1, *player2);
                 if done {
                     println!("Player 1: {}, Player 2: {}", player1, player2);
-                    if player1 > player2 {
-                        state =
-                            GameState::EndGame(EndGameState::Fireworks(FireworkState::default()));
-                    } else if player1 < player2 {
-                        state = GameState::EndGame(EndGameState::Lose(get_time()));
-                    } else if player1 == player2 {
-                        state = GameState::EndGame(EndGameState::Draw(get_time()));
-                    }
+                    state = match *winner {
+                        None => GameState::EndGame(EndGameState::Draw(get_time())),
+                        Some(color)
+                            if seats.controller(color) == Controller::Human
+                                || !seats.has_human() =>
+                        {
+                            GameState::EndGame(EndGameState::Fireworks(FireworkState::default()))
+                        }
+                        Some(_) => GameState::EndGame(EndGameState::Lose(get_time())),
+                    };
                 }
             }
             GameState::EndGame(EndGameState::Fireworks(firework_state)) => {
                 if firework_state.timer > 15.0 {
-                    state = GameState::Start;
+                    state = GameState::Menu;
                 } else {
                     launch_fireworks(firework_state);
                 }
@@ -151,14 +255,14 @@ async fn main() {
                 if get_time() - *animation_start < 5.0 {
                     draw_screen();
                 } else {
-                    state = GameState::Start;
+                    state = GameState::Menu;
                 }
             }
             GameState::EndGame(EndGameState::Lose(animation_start)) => {
                 if get_time() - *animation_start < 5.0 {
                     defeat_screen();
                 } else {
-                    state = GameState::Start;
+                    state = GameState::Menu;
                 }
             }
         }
@@ -166,6 +270,59 @@ async fn main() {
     }
 }
 
+/// Arguments optionnels : `--black=<human|ai[:niveau]>`, `--white=<...>` puis une
+/// position de départ "<64 cases> <couleur au trait>". Les joueurs ne sont lus qu'une fois le
+/// niveau choisi dans le menu.
+fn parse_args() -> (Vec<(ColorPiece, String)>, String) {
+    let mut controllers = Vec::new();
+    let mut position = Vec::new();
+    for arg in std::env::args().skip(1) {
+        let seat = arg
+            .strip_prefix("--black=")
+            .map(|controller| (Black, controller))
+            .or_else(|| {
+                arg.strip_prefix("--white=")
+                    .map(|controller| (White, controller))
+            });
+        match seat {
+            Some((color, controller)) => controllers.push((color, controller.to_string())),
+            None => position.push(arg),
+        }
+    }
+    (controllers, position.join(" "))
+}
+
+/// Les IA données sans niveau jouent à celui du menu, comme l'IA par défaut des blancs. Rien ne
+/// joue pour un joueur externe ici, il est donc ignoré.
+fn seats_with_level(controllers: &[(ColorPiece, String)], level: Difficulty) -> Seats {
+    let mut seats = Seats::new(Controller::Human, Controller::Ai(level));
+    for (color, controller) in controllers {
+        match Controller::parse_with_level(controller, level) {
+            Ok(Controller::External) => {
+                eprintln!("Joueur ignoré : joueur externe non pris en charge")
+            }
+            Ok(controller) => seats.set_controller(*color, controller),
+            Err(error) => eprintln!("Joueur ignoré : {}", error),
+        }
+    }
+    seats
+}
+
+/// Recherche de chaque IA à son niveau, limitée à `AI_NODES_PER_FRAME` positions par image.
+/// Elle est gardée d'un coup à l'autre avec ses tables.
+fn frame_searches(seats: &Seats) -> Vec<(ColorPiece, NegamaxStrategy)> {
+    [Black, White]
+        .into_iter()
+        .filter_map(|color| match seats.controller(color) {
+            Controller::Ai(level) => Some((
+                color,
+                level.search_with_budget(Budget::Nodes(AI_NODES_PER_FRAME)),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
 fn create_pieces_for_end_game(start_time: f64, delay: f64, player1: usize, player2: usize) -> bool {
     let pieces: Vec<ColorPiece> = repeat_n(Black, player1)
         .chain(repeat_n(White, player2))
@@ -224,6 +381,48 @@ fn launch_fireworks(firework_state: &mut FireworkState) {
     );
 }
 
+/// Choix du niveau de l'IA : un clic sur un niveau (ou Entrée) lance la partie.
+fn difficulty_menu(selected: Difficulty) -> Option<Difficulty> {
+    let title_width = measure_text("REVERSI", None, 64, 1.0).width;
+    draw_text("REVERSI", (screen_width() - title_width) / 2.0, 120.0, 64.0, WHITE);
+
+    let (mouse_x, mouse_y) = mouse_position();
+    let mut chosen = None;
+    for (i, difficulty) in Difficulty::ALL.into_iter().enumerate() {
+        let (width, height) = (260.0, 55.0);
+        let x = (screen_width() - width) / 2.0;
+        let y = 180.0 + i as f32 * (height + 20.0);
+        let hovered =
+            mouse_x >= x && mouse_x <= x + width && mouse_y >= y && mouse_y <= y + height;
+
+        let background = if hovered {
+            Color::new(0.25, 0.25, 0.25, 1.0)
+        } else {
+            Color::new(0.15, 0.15, 0.15, 1.0)
+        };
+        draw_rectangle(x, y, width, height, background);
+        if difficulty == selected {
+            draw_rectangle_lines(x, y, width, height, 4.0, WHITE);
+        }
+        let label = difficulty_label(difficulty);
+        let label_width = measure_text(label, None, 32, 1.0).width;
+        draw_text(label, x + (width - label_width) / 2.0, y + 37.0, 32.0, WHITE);
+
+        if hovered && is_mouse_button_pressed(MouseButton::Left) {
+            chosen = Some(difficulty);
+        }
+    }
+    if is_key_pressed(KeyCode::Enter) {
+        chosen = Some(selected);
+    }
+    chosen
+}
+
+pub fn notice_screen(text: &str) {
+    let width = measure_text(text, None, 40, 1.0).width;
+    draw_text(text, (screen_width() - width) / 2.0, 30.0, 40.0, WHITE);
+}
+
 pub fn defeat_screen() {
     draw_text(
         "You Lost !",
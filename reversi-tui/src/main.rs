@@ -0,0 +1,157 @@
+use reversi_core::application::difficulty::Difficulty;
+use reversi_core::application::seats::{Controller, Seats};
+use reversi_core::application::use_case::UseCase;
+use reversi_core::domain::board::ColorPiece::{Black, White};
+use reversi_core::domain::board::{Board, ColorPiece};
+use reversi_core::domain::moves::{Move, MoveError};
+use std::io::{self, BufRead, Write};
+
+use render::Symbols;
+use reversi_core::presentation::labels::{color_name, move_error_message, pass_message};
+mod render;
+
+struct Options {
+    seats: Seats,
+    difficulty: Difficulty,
+    symbols: Symbols,
+    position: String,
+}
+
+const HELP: &str = "Coups en notation algébrique (f5), \"u\" pour annuler, \"q\" pour quitter";
+
+/// Arguments optionnels : `--black=<human|ai|ai:<niveau>|external>`, `--white=<...>`,
+/// `--level=<beginner|intermediate|advanced|expert>`, niveau des IA données sans le leur,
+/// `--ascii` puis une position de départ "<64 cases> <couleur au trait>". Les joueurs externes
+/// jouent aussi sur l'entrée standard.
+fn parse_args() -> Options {
+    let mut options = Options {
+        seats: Seats::default(),
+        difficulty: Difficulty::default(),
+        symbols: Symbols::UNICODE,
+        position: String::new(),
+    };
+    // Lus une fois le niveau connu, quel que soit l'ordre des arguments
+    let mut controllers = vec![(Black, "human".to_string()), (White, "ai".to_string())];
+    let mut position = Vec::new();
+    for arg in std::env::args().skip(1) {
+        if arg == "--ascii" {
+            options.symbols = Symbols::ASCII;
+        } else if let Some(level) = arg.strip_prefix("--level=") {
+            match level.parse() {
+                Ok(difficulty) => options.difficulty = difficulty,
+                Err(error) => eprintln!("Niveau ignoré : {}", error),
+            }
+        } else if let Some((color, controller)) = arg
+            .strip_prefix("--black=")
+            .map(|controller| (Black, controller))
+            .or_else(|| {
+                arg.strip_prefix("--white=")
+                    .map(|controller| (White, controller))
+            })
+        {
+            controllers.push((color, controller.to_string()));
+        } else {
+            position.push(arg);
+        }
+    }
+    for (color, controller) in controllers {
+        match Controller::parse_with_level(&controller, options.difficulty) {
+            Ok(controller) => options.seats.set_controller(color, controller),
+            Err(error) => eprintln!("Joueur ignoré : {}", error),
+        }
+    }
+    options.position = position.join(" ");
+    options
+}
+
+fn print_pass(pass: Option<ColorPiece>) {
+    if let Some(color) = pass {
+        println!("{}", pass_message(color));
+    }
+}
+
+fn main() {
+    let options = parse_args();
+    let seats = options.seats;
+    let symbols = options.symbols;
+    let mut use_case = UseCase::new(options.difficulty);
+    use_case.set_seats(&seats);
+
+    let mut board: Board = if options.position.is_empty() {
+        use_case.initialize_game_use_case.execute()
+    } else {
+        use_case
+            .initialize_game_use_case
+            .execute_from_position(&options.position)
+            .unwrap_or_else(|error| {
+                eprintln!("Position ignorée : {}", error);
+                use_case.initialize_game_use_case.execute()
+            })
+    };
+
+    println!("{}", HELP);
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        let to_move = seats.to_move(&board);
+        let playable = use_case.compute_available_moves_use_case.execute(&board);
+        let marked: &[(usize, usize)] = if to_move.is_ai() { &[] } else { &playable };
+        println!();
+        print!("{}", render::board(&board, marked, &symbols));
+        println!("{}", render::score(&board, &symbols));
+
+        if let Some(score) = use_case.evaluate_game_end_use_case.execute(&board) {
+            match score.winner() {
+                Some(color) => println!("{} gagne !", color_name(color)),
+                None => println!("Match nul"),
+            }
+            return;
+        }
+
+        let color = board.current_player().color();
+        if to_move.is_ai() {
+            match use_case.play_ai_move_use_case.execute(&mut board) {
+                Ok(Some(selected_move)) => {
+                    let (x, y) = selected_move.position();
+                    println!("{} (IA) joue {}", color_name(color), Move::Play(x, y));
+                    print_pass(selected_move.pass());
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    // Le coup refusé serait proposé de nouveau, la partie ne peut pas continuer
+                    println!("Coup de l'IA refusé : {}", move_error_message(error));
+                    return;
+                }
+            }
+            continue;
+        }
+
+        print!("{} {} à jouer : ", color_name(color), symbols.piece(color));
+        // Rien à faire si la sortie est fermée, la partie continue
+        let _ = io::stdout().flush();
+        // Fin de l'entrée : la partie est abandonnée
+        let Some(Ok(line)) = lines.next() else {
+            println!();
+            return;
+        };
+        match line.trim() {
+            "" => {}
+            "q" => return,
+            "u" => {
+                // Annule aussi les coups de l'IA pour revenir au dernier coup d'un humain
+                while board.undo().is_some() && seats.to_move(&board).is_ai() {}
+            }
+            "?" => println!("{}", HELP),
+            text => match text.parse::<Move>() {
+                Ok(Move::Play(x, y)) => {
+                    match use_case.play_move_use_case.execute(&mut board, x, y) {
+                        Ok(outcome) => print_pass(outcome.pass()),
+                        Err(error) => println!("{}", move_error_message(error)),
+                    }
+                }
+                // Les passes sont jouées automatiquement
+                Ok(Move::Pass) => println!("{}", move_error_message(MoveError::MustPlay)),
+                Err(_) => println!("Coup invalide \"{}\". {}", text, HELP),
+            },
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use reversi_core::domain::board::ColorPiece::{Black, White};
+use reversi_core::domain::board::{Board, Case, ColorPiece};
+use reversi_core::presentation::labels::color_name;
+
+/// Caractères utilisés pour dessiner le plateau
+pub struct Symbols {
+    black: char,
+    white: char,
+    empty: char,
+    playable: char,
+}
+
+impl Symbols {
+    pub const UNICODE: Symbols = Symbols {
+        black: '●',
+        white: '○',
+        empty: '·',
+        playable: '+',
+    };
+
+    /// Pour les terminaux qui n'affichent pas l'Unicode
+    pub const ASCII: Symbols = Symbols {
+        black: 'X',
+        white: 'O',
+        empty: '.',
+        playable: '*',
+    };
+
+    pub fn piece(&self, color: ColorPiece) -> char {
+        match color {
+            Black => self.black,
+            White => self.white,
+        }
+    }
+}
+
+/// Plateau avec ses coordonnées, les cases jouables marquées
+pub fn board(board: &Board, playable: &[(usize, usize)], symbols: &Symbols) -> String {
+    let mut text = String::from("   a b c d e f g h\n");
+    for y in 0..8 {
+        text.push_str(&format!(" {}", y + 1));
+        for x in 0..8 {
+            let c = match board.cell(x, y) {
+                Some(Case::Piece(color)) => symbols.piece(*color),
+                _ if playable.contains(&(x, y)) => symbols.playable,
+                _ => symbols.empty,
+            };
+            text.push(' ');
+            text.push(c);
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Pièces de chaque couleur, le joueur 1 ayant toujours les noirs
+pub fn score(board: &Board, symbols: &Symbols) -> String {
+    let score = board.score();
+    format!(
+        "{} {} : {}  -  {} {} : {}",
+        color_name(Black),
+        symbols.piece(Black),
+        score.player1(),
+        color_name(White),
+        symbols.piece(White),
+        score.player2()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_mark_playable_cells_of_the_initial_board() {
+        // Given
+        let board = Board::default();
+        let playable = [(3, 2), (2, 3), (5, 4), (4, 5)];
+
+        // When
+        let text = super::board(&board, &playable, &Symbols::ASCII);
+
+        // Then
+        assert_eq!(
+            text,
+            "   a b c d e f g h\n".to_string()
+                + " 1 . . . . . . . .\n"
+                + " 2 . . . . . . . .\n"
+                + " 3 . . . * . . . .\n"
+                + " 4 . . * O X . . .\n"
+                + " 5 . . . X O * . .\n"
+                + " 6 . . . . * . . .\n"
+                + " 7 . . . . . . . .\n"
+                + " 8 . . . . . . . .\n"
+        );
+    }
+
+    #[test]
+    fn should_count_pieces_of_each_color() {
+        // Given
+        let board = Board::from_transcript("f5").expect("Transcript should be legal");
+
+        // When
+        let text = score(&board, &Symbols::ASCII);
+
+        // Then
+        assert_eq!(
+            text,
+            format!(
+                "{} X : 4  -  {} O : 1",
+                color_name(Black),
+                color_name(White)
+            )
+        );
+    }
+}